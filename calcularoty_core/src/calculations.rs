@@ -0,0 +1,161 @@
+use crate::complex::ComplexBFloat;
+use crate::consts::*;
+use crate::error::CalcError;
+use crate::types::{BFloat, ImpedanceResult, ResonanceResult, UnitType};
+use num_complex::Complex;
+use num_traits::{One, Pow, Zero};
+
+/// Solves the LC resonance relation for the quantity of `output_type`, given the other two in base units.
+/// `base1_type` tells which of the inputs is which.
+pub fn calculate_lc(base_input1: BFloat, base_input2: BFloat, base1_type: UnitType, output_type: UnitType) -> Result<BFloat, CalcError> {
+	match output_type {
+		UnitType::Hertz => {
+			if base1_type == UnitType::Henry {
+				return Ok(lc_to_f0(base_input1, base_input2));
+			}
+			Ok(lc_to_f0(base_input2, base_input1))
+		}
+
+		UnitType::Farad => {
+			if base1_type == UnitType::Henry {
+				return Ok(lf0_to_c(base_input1, base_input2));
+			}
+			Ok(lf0_to_c(base_input2, base_input1))
+		}
+
+		UnitType::Henry => {
+			if base1_type == UnitType::Farad {
+				return Ok(cf0_to_l(base_input1, base_input2));
+			}
+			Ok(cf0_to_l(base_input2, base_input1))
+		}
+	
+		_ => Err(CalcError::UnsupportedOutput(output_type))
+	}
+}
+
+/// Resonant frequency from L and C: 1/(2pi*sqrt(l*c))
+pub fn lc_to_f0(l: BFloat, c: BFloat) -> BFloat {
+	BFloat::one() / ( two_pi() * (l * c).sqrt() )
+}
+
+/// Inductance resonating with C at f0: 1/(c*(2pi*f0)²)
+pub fn cf0_to_l(c: BFloat, f0: BFloat) -> BFloat {
+	BFloat::one() / ( c * ( two_pi() * f0 ).pow(2u8) )
+}
+
+/// Capacitance resonating with L at f0: 1/(l*(2pi*f0)²)
+pub fn lf0_to_c(l: BFloat, f0: BFloat) -> BFloat {
+	BFloat::one() / ( l * ( two_pi() * f0 ).pow(2u8) )
+}
+
+/// Impedance of an inductor: jωL
+pub fn lc_inductive_reactance(l: BFloat, omega: BFloat) -> ComplexBFloat {
+	Complex::new(BFloat::zero(), omega * l)
+}
+
+/// Impedance of a capacitor: -j/(ωC)
+pub fn lc_capacitive_reactance(c: BFloat, omega: BFloat) -> ComplexBFloat {
+	Complex::new(BFloat::zero(), -BFloat::one() / (omega * c))
+}
+
+/// Impedance of a resistor: R
+pub fn resistor_impedance(r: BFloat) -> ComplexBFloat {
+	Complex::new(r, BFloat::zero())
+}
+
+/// Series RLC: Z = R + jωL - j/(ωC), a missing resistor is a short
+pub fn calculate_impedance_series(r: Option<BFloat>, l: BFloat, c: BFloat, omega: BFloat) -> ImpedanceResult {
+	let l_impedance = lc_inductive_reactance(l, omega.clone());
+	let c_impedance = lc_capacitive_reactance(c, omega);
+
+	let r_impedance = resistor_impedance(r.unwrap_or(BFloat::zero()));
+
+	let impedance = r_impedance + l_impedance.clone() + c_impedance.clone();
+	let admittance = impedance.inv();
+
+	ImpedanceResult {
+		impedance,
+		admittance,
+		inductive_reactance: l_impedance.im,
+		capacitive_reactance: c_impedance.im.abs(),
+	}
+}
+
+/// Parallel RLC: Y = 1/R + 1/(jωL) + jωC, a missing resistor is an open
+pub fn calculate_impedance_parallel(r: Option<BFloat>, l: BFloat, c: BFloat, omega: BFloat) -> ImpedanceResult {
+	let l_impedance = lc_inductive_reactance(l, omega.clone());
+	let c_impedance = lc_capacitive_reactance(c, omega);
+
+	let r_admittance = match r {
+		Some(r) => resistor_impedance(r).inv(),
+		None => ComplexBFloat::zero(),
+	};
+
+	let admittance = r_admittance + l_impedance.inv() + c_impedance.inv();
+	let impedance = admittance.inv();
+
+	ImpedanceResult {
+		impedance,
+		admittance,
+		inductive_reactance: l_impedance.im,
+		capacitive_reactance: c_impedance.im.abs(),
+	}
+}
+
+/// Resonant frequency from L and C, same as [`lc_to_f0`].
+#[inline]
+pub fn calculate_resonant_frequency(l: BFloat, c: BFloat) -> BFloat {
+	BFloat::one() / ( two_pi() * ( l * c ).sqrt() )
+}
+
+/// Characteristic impedance: sqrt(L/C)
+#[inline]
+pub fn calculate_characteristic_impedance(l: BFloat, c: BFloat) -> BFloat {
+	( l / c ).sqrt()
+}
+
+/// Series RLC resonance, ζ = R/(2*Z0), a missing resistor is a short
+pub fn calculate_resonance_series(r: Option<BFloat>, l: BFloat, c: BFloat) -> ResonanceResult {
+	let characteristic_impedance = calculate_characteristic_impedance(l.clone(), c.clone());
+
+	let damping_ratio = match r {
+		Some(r) => r / ( BFloat::from(2) * characteristic_impedance.clone() ),
+		None => BFloat::zero(),
+	};
+
+	resonance_from_damping(calculate_resonant_frequency(l, c), characteristic_impedance, damping_ratio)
+}
+
+/// Parallel RLC resonance, ζ = Z0/(2*R), a missing resistor is an open
+pub fn calculate_resonance_parallel(r: Option<BFloat>, l: BFloat, c: BFloat) -> ResonanceResult {
+	let characteristic_impedance = calculate_characteristic_impedance(l.clone(), c.clone());
+
+	let damping_ratio = match r {
+		Some(r) => characteristic_impedance.clone() / ( BFloat::from(2) * r ),
+		None => BFloat::zero(),
+	};
+
+	resonance_from_damping(calculate_resonant_frequency(l, c), characteristic_impedance, damping_ratio)
+}
+
+//INFO: Q = 1/(2ζ), BW = f0/Q, f1,2 = f0*(sqrt(1+ζ²) ∓ ζ)
+fn resonance_from_damping(f0: BFloat, characteristic_impedance: BFloat, damping_ratio: BFloat) -> ResonanceResult {
+	let quality_factor = match damping_ratio.is_zero() {
+		true => BFloat::from(f64::INFINITY),
+		false => BFloat::one() / ( BFloat::from(2) * damping_ratio.clone() ),
+	};
+
+	let bandwidth = BFloat::from(2) * damping_ratio.clone() * f0.clone();
+	let spread = ( BFloat::one() + damping_ratio.clone().pow(2u8) ).sqrt();
+
+	ResonanceResult {
+		lower_frequency: f0.clone() * ( spread.clone() - damping_ratio.clone() ),
+		upper_frequency: f0.clone() * ( spread + damping_ratio.clone() ),
+		resonant_frequency: f0,
+		characteristic_impedance,
+		damping_ratio,
+		quality_factor,
+		bandwidth,
+	}
+}
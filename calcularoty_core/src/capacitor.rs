@@ -0,0 +1,151 @@
+//! Capacitors from their geometry: parallel plates and coaxial cylinders, and the other way
+//! round, the one dimension that gives a target capacitance.
+//!
+//! Plates are taken as wide against their separation, the fringing field at the edges is left
+//! out and adds a few percent once the separation is no longer small. The coaxial formula is
+//! exact for a long structure, the ends add to it the same way.
+//!
+//! Sizes are in meters, areas in m², capacitances in F.
+
+use std::{fmt::Display, str::FromStr};
+
+use num_traits::Zero;
+
+use crate::consts::{epsilon0, two_pi, z0_free_space};
+use crate::error::CalcError;
+use crate::types::BFloat;
+
+/// Names of the shapes, in the order [`CapacitorShape::from_str`] knows them.
+pub const CAPACITOR_SHAPES: [&str; 2] = ["Parallel plate", "Coaxial"];
+
+/// What a parallel-plate capacitor can be solved for.
+pub const PLATE_UNKNOWNS: [&str; 2] = ["Separation", "Area"];
+
+/// What a coaxial capacitor can be solved for.
+pub const COAX_UNKNOWNS: [&str; 2] = ["Length", "Outer diameter"];
+
+/// Relative permittivities of common dielectrics, typical values at low frequencies and room
+/// temperature. Ceramics and electrolytic oxides vary too much with the mix to be listed.
+pub const DIELECTRICS: [(&str, &str); 15] = [
+	("Vacuum", "1"),
+	("Air", "1.00059"),
+	("PTFE", "2.1"),
+	("Polypropylene", "2.2"),
+	("Polyethylene", "2.25"),
+	("Polystyrene", "2.6"),
+	("Polyester (PET)", "3.3"),
+	("Polyimide", "3.4"),
+	("Paper", "3.5"),
+	("Silicon dioxide", "3.9"),
+	("FR-4", "4.4"),
+	("Borosilicate glass", "4.6"),
+	("Mica", "6"),
+	("Alumina", "9.8"),
+	("Water", "80.1"),
+];
+
+/// How the electrodes face each other.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CapacitorShape {
+	/// Two flat plates of the same area.
+	#[default]
+	ParallelPlate,
+	/// A round inner conductor inside a tube, a coaxial cable or a cylindrical capacitor.
+	Coaxial,
+}
+
+impl FromStr for CapacitorShape {
+	type Err = CalcError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim().to_lowercase().replace(['-', ' '], "").as_str() {
+			"parallelplate" | "plate" | "plates" => Ok(CapacitorShape::ParallelPlate),
+			"coaxial" | "coax" | "cylindrical" => Ok(CapacitorShape::Coaxial),
+			_ => Err(CalcError::InvalidCapacitor(format!("'{s}' is not a capacitor shape"))),
+		}
+	}
+}
+
+impl Display for CapacitorShape {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			CapacitorShape::ParallelPlate => write!(f, "{}", CAPACITOR_SHAPES[0]),
+			CapacitorShape::Coaxial => write!(f, "{}", CAPACITOR_SHAPES[1]),
+		}
+	}
+}
+
+/// The relative permittivity listed for `name` in [`DIELECTRICS`], ignoring case.
+pub fn dielectric(name: &str) -> Option<BFloat> {
+	DIELECTRICS
+		.iter()
+		.find(|(listed, _)| listed.eq_ignore_ascii_case(name.trim()))
+		.and_then(|(_, permittivity)| permittivity.parse().ok())
+}
+
+/// C = ε0·εr·A/d.
+pub fn plate_capacitance(area: &BFloat, separation: &BFloat, permittivity: &BFloat) -> Result<BFloat, CalcError> {
+	positive(&[area, separation, permittivity])?;
+
+	Ok(epsilon0() * permittivity.clone() * area.clone() / separation.clone())
+}
+
+/// The separation that gives `target` between plates of `area`, d = ε0·εr·A/C.
+pub fn plate_separation(area: &BFloat, permittivity: &BFloat, target: &BFloat) -> Result<BFloat, CalcError> {
+	positive(&[area, permittivity, target])?;
+
+	Ok(epsilon0() * permittivity.clone() * area.clone() / target.clone())
+}
+
+/// The plate area that gives `target` at `separation`, A = C·d/(ε0·εr).
+pub fn plate_area(separation: &BFloat, permittivity: &BFloat, target: &BFloat) -> Result<BFloat, CalcError> {
+	positive(&[separation, permittivity, target])?;
+
+	Ok(target.clone() * separation.clone() / (epsilon0() * permittivity.clone()))
+}
+
+/// C = 2π·ε0·εr·ℓ/ln(D/d), `inner` the diameter of the inner conductor, `outer` the inside
+/// diameter of the outer one.
+pub fn coax_capacitance(inner: &BFloat, outer: &BFloat, length: &BFloat, permittivity: &BFloat) -> Result<BFloat, CalcError> {
+	positive(&[length, permittivity])?;
+
+	Ok(two_pi() * epsilon0() * permittivity.clone() * length.clone() / coax_log(inner, outer)?)
+}
+
+/// The length that gives `target`, ℓ = C·ln(D/d)/(2π·ε0·εr).
+pub fn coax_length(inner: &BFloat, outer: &BFloat, permittivity: &BFloat, target: &BFloat) -> Result<BFloat, CalcError> {
+	positive(&[permittivity, target])?;
+
+	Ok(target.clone() * coax_log(inner, outer)? / (two_pi() * epsilon0() * permittivity.clone()))
+}
+
+/// The outer diameter that gives `target` over `length`, D = d·exp(2π·ε0·εr·ℓ/C).
+pub fn coax_outer_diameter(inner: &BFloat, length: &BFloat, permittivity: &BFloat, target: &BFloat) -> Result<BFloat, CalcError> {
+	positive(&[inner, length, permittivity, target])?;
+
+	Ok(inner.clone() * (two_pi() * epsilon0() * permittivity.clone() * length.clone() / target.clone()).exp())
+}
+
+/// Characteristic impedance of the coaxial line, Z0 = η0·ln(D/d)/(2π·√εr).
+pub fn coax_impedance(inner: &BFloat, outer: &BFloat, permittivity: &BFloat) -> Result<BFloat, CalcError> {
+	positive(&[permittivity])?;
+
+	Ok(z0_free_space() * coax_log(inner, outer)? / (two_pi() * permittivity.sqrt()))
+}
+
+//INFO: ln(D/d), the conductors have to fit one inside the other
+fn coax_log(inner: &BFloat, outer: &BFloat) -> Result<BFloat, CalcError> {
+	positive(&[inner, outer])?;
+
+	if outer <= inner {
+		return Err(CalcError::InvalidCapacitor("the outer diameter must be above the inner one".to_string()));
+	}
+
+	Ok((outer.clone() / inner.clone()).ln())
+}
+
+fn positive(values: &[&BFloat]) -> Result<(), CalcError> {
+	match values.iter().all(|value| **value > BFloat::zero()) {
+		true => Ok(()),
+		false => Err(CalcError::NotPositive),
+	}
+}
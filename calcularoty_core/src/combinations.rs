@@ -0,0 +1,112 @@
+//! Pairs of preferred parts that together come closer to a target L or C than any single one.
+//!
+//! Inductors add in series and capacitors in parallel, the other connection adds reciprocals.
+//! For each connection one part runs over every preferred value that can carry the pair, the
+//! other is what is left of the target, snapped down and up to the series.
+
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::error::CalcError;
+use crate::eseries::{relative_error, ESeries};
+use crate::types::{BFloat, UnitType};
+
+/// How the two parts of a pair are wired.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connection {
+	Series,
+	Parallel,
+}
+
+impl Display for Connection {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Connection::Series => write!(f, "series"),
+			Connection::Parallel => write!(f, "parallel"),
+		}
+	}
+}
+
+/// Two preferred parts, the larger first, and what they make together.
+#[derive(Clone, Debug)]
+pub struct Combination {
+	pub first: BFloat,
+	pub second: BFloat,
+	pub connection: Connection,
+	pub value: BFloat,
+	/// Relative error against the target, 0.01 is 1 % above.
+	pub error: BFloat,
+}
+
+//INFO: true when the connection sums the values, L in series or C in parallel
+fn adds(unit_type: UnitType, connection: Connection) -> Result<bool, CalcError> {
+	match (unit_type, connection) {
+		(UnitType::Henry, Connection::Series) | (UnitType::Farad, Connection::Parallel) => Ok(true),
+		(UnitType::Henry, Connection::Parallel) | (UnitType::Farad, Connection::Series) => Ok(false),
+		_ => Err(CalcError::UnsupportedOutput(unit_type)),
+	}
+}
+
+/// What two parts of `unit_type` make when wired by `connection`.
+pub fn combine(first: &BFloat, second: &BFloat, unit_type: UnitType, connection: Connection) -> Result<BFloat, CalcError> {
+	match adds(unit_type, connection)? {
+		true => Ok(first.clone() + second.clone()),
+		false => Ok(first.clone() * second.clone() / (first.clone() + second.clone())),
+	}
+}
+
+/// Pairs of `series` parts closest to `target`, best first, at most `limit` of them. With a
+/// `tolerance`, a relative error like 0.01, pairs further off than it are left out.
+pub fn best_pairs(target: &BFloat, unit_type: UnitType, series: ESeries, tolerance: Option<&BFloat>, limit: usize) -> Result<Vec<Combination>, CalcError> {
+	let two = BFloat::from(2);
+	let mut pairs: Vec<Combination> = Vec::new();
+
+	for connection in [Connection::Series, Connection::Parallel] {
+		let adds = adds(unit_type, connection)?;
+
+		//INFO: summing, the larger part is between half the target and the target, with
+		// reciprocals the smaller part is between the target and twice it
+		let firsts = match adds {
+			true => series.values_between(&(target.clone() / two.clone()), target)?,
+			false => series.values_between(target, &(target.clone() * two.clone()))?,
+		};
+
+		for first in firsts {
+			let rest = match adds {
+				true => target.clone() - first.clone(),
+				false => first.clone() * target.clone() / (first.clone() - target.clone()),
+			};
+
+			//INFO: the first part alone already is the target, nothing is left for a second
+			let Ok((lower, upper)) = series.neighbours(&rest) else {
+				continue;
+			};
+
+			for second in [lower, upper] {
+				let value = combine(&first, &second, unit_type, connection)?;
+				let error = relative_error(&value, target);
+
+				let (first, second) = match first >= second {
+					true => (first.clone(), second),
+					false => (second, first.clone()),
+				};
+
+				let duplicate = pairs
+					.iter()
+					.any(|pair| pair.connection == connection && pair.first == first && pair.second == second);
+
+				if !duplicate {
+					pairs.push(Combination { first, second, connection, value, error });
+				}
+			}
+		}
+	}
+
+	if let Some(tolerance) = tolerance {
+		pairs.retain(|pair| pair.error.abs() <= *tolerance);
+	}
+
+	pairs.sort_by(|a, b| a.error.abs().partial_cmp(&b.error.abs()).unwrap_or(Ordering::Equal));
+	pairs.truncate(limit);
+
+	Ok(pairs)
+}
@@ -0,0 +1,42 @@
+//! Complex numbers over [`BFloat`], for impedances and admittances.
+//!
+//! Addition, subtraction, multiplication, division, [`Complex::inv`] and [`Complex::conj`] come
+//! from `num_complex` itself. Its magnitude, argument and polar helpers need `Float`, which a
+//! non-`Copy` type like [`BFloat`] cannot implement, so [`ComplexExt`] provides them instead.
+
+use num_complex::Complex;
+
+use crate::types::BFloat;
+
+/// A complex value with [`BFloat`] real and imaginary parts.
+pub type ComplexBFloat = Complex<BFloat>;
+
+/// Polar form and magnitude for [`ComplexBFloat`].
+pub trait ComplexExt {
+	/// Magnitude |z|.
+	fn magnitude(&self) -> BFloat;
+	/// Argument arg(z), in radians, in the range (-pi, pi].
+	fn argument(&self) -> BFloat;
+	/// Magnitude and argument.
+	fn to_polar(&self) -> (BFloat, BFloat);
+	/// The value with magnitude `r` and argument `theta`, in radians.
+	fn from_polar(r: BFloat, theta: BFloat) -> Self;
+}
+
+impl ComplexExt for ComplexBFloat {
+	fn magnitude(&self) -> BFloat {
+		self.norm_sqr().sqrt()
+	}
+
+	fn argument(&self) -> BFloat {
+		BFloat::atan2(&self.im, &self.re)
+	}
+
+	fn to_polar(&self) -> (BFloat, BFloat) {
+		(self.magnitude(), self.argument())
+	}
+
+	fn from_polar(r: BFloat, theta: BFloat) -> Self {
+		Complex::new(r.clone() * theta.cos(), r * theta.sin())
+	}
+}
@@ -0,0 +1,43 @@
+use num_traits::One;
+
+use crate::{conversions::pow10, precision::{precision, rounding_mode, with_consts}, types::BFloat};
+
+//INFO: computed at the current precision, see crate::precision
+pub fn pi() -> BFloat {
+	with_consts(|consts| BFloat::from(consts.pi(precision(), rounding_mode())))
+}
+
+pub fn two_pi() -> BFloat {
+	pi() * BFloat::from(2)
+}
+
+//INFO: magnetic constant μ0 in H/m, the 4π·10⁻⁷ defined before 2019, the measured value is within 10⁻⁹ of it
+pub fn mu0() -> BFloat {
+	pi() * BFloat::from(4) * pow10(-7)
+}
+
+//INFO: speed of light in m/s, exact by the definition of the meter
+pub fn c0() -> BFloat {
+	BFloat::from(299_792_458u32)
+}
+
+//INFO: electric constant ε0 in F/m, 1/(μ0·c0²), follows the μ0 above
+pub fn epsilon0() -> BFloat {
+	BFloat::one() / (mu0() * c0() * c0())
+}
+
+//INFO: impedance of free space in Ω, μ0·c0, about 376.73
+pub fn z0_free_space() -> BFloat {
+	mu0() * c0()
+}
+
+pub const HERTZ_BASE_TYPE: &str = "Hz";
+pub const FARAD_BASE_TYPE: &str = "F";
+pub const HENRY_BASE_TYPE: &str = "H";
+pub const OHM_BASE_TYPE: &str = "Ω";
+pub const SIEMENS_BASE_TYPE: &str = "S";
+pub const METER_BASE_TYPE: &str = "m";
+pub const SQUARE_METER_BASE_TYPE: &str = "m²";
+
+//INFO: output unit that picks the engineering prefix, "Auto Hz" when the family is not implied
+pub const AUTO_UNIT: &str = "Auto";
@@ -0,0 +1,97 @@
+//! Conversions between the prefixes of a unit family.
+
+use num_traits::{One, Pow, Zero};
+use phf::OrderedMap;
+use crate::consts::{AUTO_UNIT, two_pi};
+use crate::error::CalcError;
+use crate::parsing::EngineeringValue;
+use crate::types::*;
+use crate::units::*;
+
+/// Converts `unit`, expressed in `unit_label`, to `target_unit`. Both labels must belong to `unit_type`.
+pub fn convert_measure(unit: BFloat, unit_type: &UnitType, unit_label: &str, target_unit: &str) -> Result<BFloat, CalcError> {
+	unit_convert(unit, unit_label, target_unit, unit_map(unit_type)?)
+}
+
+/// Converts `unit`, expressed in `unit_label`, to the base unit of `unit_type`.
+pub fn convert_to_base(unit: BFloat, unit_type: &UnitType, unit_label: &str) -> Result<BFloat, CalcError> {
+	convert_measure(unit, unit_type, unit_label, unit_type.base_unit())
+}
+
+/// Converts a value in the base unit of `unit_type` for display in `target_unit`. An `Auto` target
+/// picks the prefix putting the value in [1, 1000), and that prefixed label is returned alongside.
+pub fn convert_for_output(value: BFloat, unit_type: &UnitType, target_unit: &str) -> Result<(BFloat, Option<&'static str>), CalcError> {
+	if !target_unit.starts_with(AUTO_UNIT) {
+		return Ok((convert_measure(value, unit_type, unit_type.base_unit(), target_unit)?, None));
+	}
+
+	let label = best_prefix(&value, unit_map(unit_type)?);
+
+	Ok((convert_measure(value, unit_type, unit_type.base_unit(), label)?, Some(label)))
+}
+
+//INFO: engineering notation, the largest prefix not bigger than the value, clamped to the table
+fn best_prefix(value: &BFloat, map: &'static OrderedMap<&'static str, i32>) -> &'static str {
+	let magnitude = value.abs();
+
+	if magnitude.is_zero() || magnitude.0.is_nan() || magnitude.0.is_inf() {
+		return map.entries().find(|(_, exponent)| **exponent == 0).map(|(label, _)| *label).unwrap();
+	}
+
+	let mut engineering = map.entries().filter(|(_, exponent)| *exponent % 3 == 0);
+	let mut best = *engineering.next().unwrap().0;
+
+	for (label, exponent) in engineering {
+		if magnitude < pow10(*exponent) {
+			break;
+		}
+		best = *label;
+	}
+
+	best
+}
+
+fn unit_map(unit_type: &UnitType) -> Result<&'static OrderedMap<&'static str, i32>, CalcError> {
+	match unit_type {
+		UnitType::Hertz => Ok(&HERTZ_UNITS),
+		UnitType::Farad => Ok(&FARAD_UNITS),
+		UnitType::Henry => Ok(&HENRY_UNITS),
+		UnitType::Ohm => Ok(&OHM_UNITS),
+		UnitType::Siemens => Ok(&SIEMENS_UNITS),
+		UnitType::Meter => Ok(&METER_UNITS),
+		UnitType::SquareMeter => Ok(&SQUARE_METER_UNITS),
+		UnitType::NotSelected => Err(CalcError::UnitNotSelected),
+	}
+}
+
+fn unit_convert(unit: BFloat, unit_label: &str, target_unit: &str, map: &OrderedMap<&str, i32>) -> Result<BFloat, CalcError> {
+	let target_ratio = *map.get(target_unit).ok_or(CalcError::UnknownUnit(target_unit.to_string()))?;
+	let input_ratio = *map.get(unit_label).ok_or(CalcError::UnknownUnit(unit_label.to_string()))?;
+	let ratio = input_ratio - target_ratio;
+
+	Ok(unit * pow10(ratio))
+}
+
+/// 10 raised to `exponent`, exact for positive exponents.
+pub fn pow10(exponent: i32) -> BFloat {
+	let scale = BFloat::from(10).pow(exponent.unsigned_abs() as usize);
+
+	if exponent < 0 {
+		return BFloat::one() / scale;
+	}
+	scale
+}
+
+/// Parses the text of an input field and converts it to the base unit. `unit_label`, the unit
+/// picked next to the field, only applies when no prefix or unit symbol was typed.
+pub fn parse_to_base(text: &str, unit_type: &UnitType, unit_label: &str) -> Result<BFloat, CalcError> {
+	let value = EngineeringValue::parse_for(text, unit_type)?;
+
+	value.to_base(unit_type, unit_label)
+}
+
+/// Angular frequency ω = 2πf.
+#[inline]
+pub fn get_omega(f: BFloat) -> BFloat {
+	f * two_pi()
+}
@@ -0,0 +1,197 @@
+//! Inductors wound on magnetic cores: the inductance from the core's A_L value, and the turns
+//! a target inductance needs.
+//!
+//! A_L is the inductance of one turn, the inductance grows with the square of the turns. The
+//! catalog is plain text, one core per line, so it can be edited by hand:
+//!
+//! ```text
+//! # name; kind; A_L (nH); μ; outer diameter (mm); inner diameter (mm); height (mm)
+//! T50-2; toroid; 4.9; 10; 12.7; 7.7; 4.83
+//! ```
+//!
+//! Values take the usual prefixes and units, bare numbers are in the units of the header. Sizes
+//! read like a coil's, `12.7mm` and `12.7` are 12.7 mm while `12.7m` is 12.7 m. A
+//! toroid may leave A_L as `-`, it is then worked out from μ and the dimensions. The inner
+//! diameter of a pot core is its centre hole, the height that of the core pair.
+
+use std::{fmt::Display, str::FromStr};
+
+use num_traits::Zero;
+
+use crate::consts::{mu0, two_pi};
+use crate::conversions::parse_to_base;
+use crate::error::CalcError;
+use crate::types::{BFloat, UnitType};
+
+/// The catalog shipped with the app, common iron powder and ferrite toroids and gapped pot cores.
+/// A_L values are the makers' nominal ones, the spread between batches is often ±10 % or more.
+pub const BUNDLED_CATALOG: &str = "\
+# name; kind; A_L (nH); μ; outer diameter (mm); inner diameter (mm); height (mm)
+# A_L is the inductance of one turn, '-' works it out from μ and the dimensions of a toroid
+# iron powder, mix 2 (red, 0.25-10 MHz)
+T37-2; toroid; 4.0; 10; 9.53; 5.21; 3.25
+T50-2; toroid; 4.9; 10; 12.7; 7.70; 4.83
+T68-2; toroid; 5.7; 10; 17.5; 9.40; 4.83
+T80-2; toroid; 5.5; 10; 20.2; 12.6; 6.35
+T106-2; toroid; 13.5; 10; 26.9; 14.5; 11.1
+T130-2; toroid; 11.0; 10; 33.0; 19.8; 11.1
+T200-2; toroid; 12.0; 10; 50.8; 31.8; 14.0
+# iron powder, mix 6 (yellow, 2-30 MHz)
+T37-6; toroid; 3.0; 8; 9.53; 5.21; 3.25
+T50-6; toroid; 4.0; 8; 12.7; 7.70; 4.83
+T68-6; toroid; 4.7; 8; 17.5; 9.40; 4.83
+T80-6; toroid; 4.5; 8; 20.2; 12.6; 6.35
+# iron powder, mix 26 (yellow-white, power chokes)
+T50-26; toroid; 33.0; 75; 12.7; 7.70; 4.83
+T106-26; toroid; 90.0; 75; 26.9; 14.5; 11.1
+# ferrite, mix 43 and 77
+FT37-43; toroid; 420; 800; 9.53; 4.75; 3.18
+FT50-43; toroid; 523; 800; 12.7; 7.15; 4.90
+FT82-43; toroid; 557; 800; 21.0; 13.1; 6.35
+FT114-43; toroid; 603; 800; 29.0; 19.0; 7.50
+FT37-77; toroid; 1240; 2000; 9.53; 4.75; 3.18
+FT50-77; toroid; 1100; 2000; 12.7; 7.15; 4.90
+FT114-77; toroid; 1270; 2000; 29.0; 19.0; 7.50
+# gapped ferrite pot cores, other gaps have other A_L values
+P14/8-100; pot; 100; 2000; 14.3; 3.0; 8.4
+P18/11-160; pot; 160; 2000; 18.4; 3.0; 10.6
+P26/16-250; pot; 250; 2000; 26.0; 5.4; 16.1
+P36/22-400; pot; 400; 2000; 35.9; 5.4; 21.8
+";
+
+//INFO: the units bare numbers in the catalog are in
+const AL_UNIT: &str = "nH";
+const SIZE_UNIT: &str = "mm";
+
+/// What the core is shaped like.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CoreKind {
+	/// A ring, the winding goes through the hole.
+	#[default]
+	Toroid,
+	/// Two cups closed around a bobbin.
+	Pot,
+}
+
+impl FromStr for CoreKind {
+	type Err = CalcError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim().to_lowercase().as_str() {
+			"toroid" | "ring" => Ok(CoreKind::Toroid),
+			"pot" | "potcore" => Ok(CoreKind::Pot),
+			_ => Err(CalcError::InvalidCatalog(format!("'{s}' is not a core kind, expected toroid or pot"))),
+		}
+	}
+}
+
+impl Display for CoreKind {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			CoreKind::Toroid => write!(f, "toroid"),
+			CoreKind::Pot => write!(f, "pot core"),
+		}
+	}
+}
+
+/// One catalog entry, sizes in meters.
+#[derive(Clone, Debug)]
+pub struct Core {
+	pub name: String,
+	pub kind: CoreKind,
+	/// Inductance of one turn in H, worked out when the catalog left it out.
+	pub al: BFloat,
+	/// Relative permeability of the material.
+	pub permeability: BFloat,
+	pub outer_diameter: BFloat,
+	pub inner_diameter: BFloat,
+	pub height: BFloat,
+}
+
+/// The inductance of `turns` on a core with `al`, L = A_L·N².
+pub fn al_inductance(al: &BFloat, turns: &BFloat) -> Result<BFloat, CalcError> {
+	if *al <= BFloat::zero() || *turns <= BFloat::zero() {
+		return Err(CalcError::NotPositive);
+	}
+
+	Ok(al.clone() * turns.clone() * turns.clone())
+}
+
+/// The turns, not rounded, that give `target` on a core with `al`, N = √(L/A_L).
+pub fn al_turns(al: &BFloat, target: &BFloat) -> Result<BFloat, CalcError> {
+	if *al <= BFloat::zero() || *target <= BFloat::zero() {
+		return Err(CalcError::NotPositive);
+	}
+
+	Ok((target.clone() / al.clone()).sqrt())
+}
+
+/// A_L of a toroid of rectangular section, μ0·μ·h·ln(OD/ID)/2π. Gaps, if any, are not in it.
+pub fn toroid_al(permeability: &BFloat, outer_diameter: &BFloat, inner_diameter: &BFloat, height: &BFloat) -> Result<BFloat, CalcError> {
+	if *permeability <= BFloat::zero() || *inner_diameter <= BFloat::zero() || *height <= BFloat::zero() {
+		return Err(CalcError::NotPositive);
+	}
+	if outer_diameter <= inner_diameter {
+		return Err(CalcError::InvalidCatalog("the outer diameter must be above the inner one".to_string()));
+	}
+
+	Ok(mu0() * permeability.clone() * height.clone() * (outer_diameter.clone() / inner_diameter.clone()).ln() / two_pi())
+}
+
+/// Reads a catalog, skipping empty lines and `#` comments. Errors name the line they are on.
+pub fn parse_catalog(text: &str) -> Result<Vec<Core>, CalcError> {
+	let mut cores = Vec::new();
+
+	for (number, line) in text.lines().enumerate() {
+		let line = line.trim();
+
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+
+		//INFO: keep the reason, not the "invalid core catalog" in front of it
+		let core = parse_core(line).map_err(|error| match error {
+			CalcError::InvalidCatalog(reason) => CalcError::InvalidCatalog(format!("line {}: {reason}", number + 1)),
+			error => CalcError::InvalidCatalog(format!("line {}: {error}", number + 1)),
+		})?;
+
+		if cores.iter().any(|other: &Core| other.name == core.name) {
+			return Err(CalcError::InvalidCatalog(format!("line {}: '{}' is listed twice", number + 1, core.name)));
+		}
+
+		cores.push(core);
+	}
+
+	Ok(cores)
+}
+
+fn parse_core(line: &str) -> Result<Core, CalcError> {
+	let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+
+	let &[name, kind, al, permeability, outer_diameter, inner_diameter, height] = fields.as_slice() else {
+		return Err(CalcError::InvalidCatalog(format!("expected 7 fields split by ';', found {}", fields.len())));
+	};
+
+	if name.is_empty() {
+		return Err(CalcError::InvalidCatalog("the core needs a name".to_string()));
+	}
+
+	let size = |text: &str| parse_to_base(text, &UnitType::Meter, SIZE_UNIT);
+
+	let kind: CoreKind = kind.parse()?;
+	let permeability: BFloat = permeability.parse()?;
+	let outer_diameter = size(outer_diameter)?;
+	let inner_diameter = size(inner_diameter)?;
+	let height = size(height)?;
+
+	let al = match (al, kind) {
+		("-", CoreKind::Toroid) => toroid_al(&permeability, &outer_diameter, &inner_diameter, &height)?,
+		("-", CoreKind::Pot) => return Err(CalcError::InvalidCatalog("a pot core needs its A_L, it depends on the gap".to_string())),
+		(al, _) => parse_to_base(al, &UnitType::Henry, AL_UNIT)?,
+	};
+
+	if al <= BFloat::zero() {
+		return Err(CalcError::NotPositive);
+	}
+
+	Ok(Core { name: name.to_string(), kind, al, permeability, outer_diameter, inner_diameter, height })
+}
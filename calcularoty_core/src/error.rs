@@ -0,0 +1,67 @@
+//! Errors surfaced by parsing, unit conversion and calculation.
+
+use std::{error::Error, fmt::Display};
+
+use crate::types::UnitType;
+
+/// Everything that can go wrong between the text of an input field and a result.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalcError {
+	/// The field was left empty.
+	EmptyInput,
+	/// The text is not a number.
+	InvalidNumber(String),
+	/// The unit label is not part of the expected unit table.
+	UnknownUnit(String),
+	/// No unit was chosen for a value.
+	UnitNotSelected,
+	/// The calculation cannot produce a quantity of this type.
+	UnsupportedOutput(UnitType),
+	/// Fewer known values than the calculation needs.
+	MissingInputs,
+	/// The number style is not one of the known ones.
+	UnknownFormat(String),
+	/// The network text is malformed, with the reason.
+	InvalidNetwork(String),
+	/// The sweep bounds or point count cannot make a sweep, with the reason.
+	InvalidSweep(String),
+	/// The name is not one of the E series.
+	UnknownSeries(String),
+	/// The sample count or another analysis setting is out of range, with the reason.
+	InvalidAnalysis(String),
+	/// The coil dimensions or turns cannot make a coil, with the reason.
+	InvalidCoil(String),
+	/// The capacitor dimensions cannot make a capacitor, with the reason.
+	InvalidCapacitor(String),
+	/// An area was typed with a prefix but no unit, which leaves open whether the prefix is squared.
+	AreaPrefix,
+	/// A line of the core catalog cannot be read, with the line and the reason.
+	InvalidCatalog(String),
+	/// Only values above zero have a place in a series or on a log scale.
+	NotPositive,
+}
+
+impl Display for CalcError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			CalcError::EmptyInput => write!(f, "value is empty"),
+			CalcError::InvalidNumber(text) => write!(f, "'{text}' is not a number"),
+			CalcError::UnknownUnit(unit) => write!(f, "'{unit}' is not a valid unit here"),
+			CalcError::UnitNotSelected => write!(f, "select a unit"),
+			CalcError::UnsupportedOutput(unit_type) => write!(f, "cannot calculate a {unit_type:?} value"),
+			CalcError::MissingInputs => write!(f, "at least two values are needed"),
+			CalcError::UnknownFormat(style) => write!(f, "'{style}' is not a number format"),
+			CalcError::InvalidNetwork(reason) => write!(f, "invalid network: {reason}"),
+			CalcError::InvalidSweep(reason) => write!(f, "invalid sweep: {reason}"),
+			CalcError::UnknownSeries(name) => write!(f, "'{name}' is not an E series"),
+			CalcError::InvalidAnalysis(reason) => write!(f, "invalid analysis: {reason}"),
+			CalcError::InvalidCoil(reason) => write!(f, "invalid coil: {reason}"),
+			CalcError::InvalidCapacitor(reason) => write!(f, "invalid capacitor: {reason}"),
+			CalcError::AreaPrefix => write!(f, "write an area with its unit after the prefix, e.g. 10mm²"),
+			CalcError::InvalidCatalog(reason) => write!(f, "invalid core catalog: {reason}"),
+			CalcError::NotPositive => write!(f, "value must be above 0"),
+		}
+	}
+}
+
+impl Error for CalcError {}
@@ -0,0 +1,169 @@
+//! IEC 60063 preferred values, the E series parts are sold in.
+//!
+//! E6, E12 and E24 are every 4th, 2nd and 1st value of the two digit E24 table, E48, E96 and
+//! E192 the same of the three digit E192 table. A value repeats in every decade.
+
+use std::{fmt::Display, str::FromStr};
+
+use num_traits::{One, Zero};
+
+use crate::conversions::pow10;
+use crate::error::CalcError;
+use crate::types::BFloat;
+
+/// Names of the series, in the order [`ESeries::from_str`] knows them.
+pub const E_SERIES_NAMES: [&str; 6] = ["E6", "E12", "E24", "E48", "E96", "E192"];
+
+const E24: [u16; 24] = [
+	10, 11, 12, 13, 15, 16, 18, 20, 22, 24, 27, 30,
+	33, 36, 39, 43, 47, 51, 56, 62, 68, 75, 82, 91,
+];
+
+const E192: [u16; 192] = [
+	100, 101, 102, 104, 105, 106, 107, 109, 110, 111, 113, 114, 115, 117, 118, 120,
+	121, 123, 124, 126, 127, 129, 130, 132, 133, 135, 137, 138, 140, 142, 143, 145,
+	147, 149, 150, 152, 154, 156, 158, 160, 162, 164, 165, 167, 169, 172, 174, 176,
+	178, 180, 182, 184, 187, 189, 191, 193, 196, 198, 200, 203, 205, 208, 210, 213,
+	215, 218, 221, 223, 226, 229, 232, 234, 237, 240, 243, 246, 249, 252, 255, 258,
+	261, 264, 267, 271, 274, 277, 280, 284, 287, 291, 294, 298, 301, 305, 309, 312,
+	316, 320, 324, 328, 332, 336, 340, 344, 348, 352, 357, 361, 365, 370, 374, 379,
+	383, 388, 392, 397, 402, 407, 412, 417, 422, 427, 432, 437, 442, 448, 453, 459,
+	464, 470, 475, 481, 487, 493, 499, 505, 511, 517, 523, 530, 536, 542, 549, 556,
+	562, 569, 576, 583, 590, 597, 604, 612, 619, 626, 634, 642, 649, 657, 665, 673,
+	681, 690, 698, 706, 715, 723, 732, 741, 750, 759, 768, 777, 787, 796, 806, 816,
+	825, 835, 845, 856, 866, 876, 887, 898, 909, 920, 931, 942, 953, 965, 976, 988,
+];
+
+/// One of the standard series of preferred values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ESeries {
+	E6,
+	E12,
+	E24,
+	E48,
+	E96,
+	E192,
+}
+
+impl FromStr for ESeries {
+	type Err = CalcError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim().to_uppercase().as_str() {
+			"E6" => Ok(ESeries::E6),
+			"E12" => Ok(ESeries::E12),
+			"E24" => Ok(ESeries::E24),
+			"E48" => Ok(ESeries::E48),
+			"E96" => Ok(ESeries::E96),
+			"E192" => Ok(ESeries::E192),
+			_ => Err(CalcError::UnknownSeries(s.to_string())),
+		}
+	}
+}
+
+impl Display for ESeries {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{self:?}")
+	}
+}
+
+impl ESeries {
+	//INFO: (table, step through it, digits of each value)
+	fn table(&self) -> (&'static [u16], usize, i32) {
+		match self {
+			ESeries::E6 => (&E24, 4, 2),
+			ESeries::E12 => (&E24, 2, 2),
+			ESeries::E24 => (&E24, 1, 2),
+			ESeries::E48 => (&E192, 4, 3),
+			ESeries::E96 => (&E192, 2, 3),
+			ESeries::E192 => (&E192, 1, 3),
+		}
+	}
+
+	/// The values of one decade, 10..100 or 100..1000.
+	pub fn decade(&self) -> Vec<u16> {
+		let (table, step, _) = self.table();
+		table.iter().step_by(step).copied().collect()
+	}
+
+	/// The nearest preferred values at or below and at or above `value`, equal when it is one.
+	pub fn neighbours(&self, value: &BFloat) -> Result<(BFloat, BFloat), CalcError> {
+		let (scaled, exponent) = self.scale_into_decade(value)?;
+		let decade = self.decade();
+
+		let low = BFloat::from(decade[0]);
+		let high = low.clone() * BFloat::from(10);
+
+		//INFO: a value within 1e-12 of a preferred one counts as that one; values typed as f64 or passed
+		//INFO: through a few divisions are off by far more than the working precision
+		let tolerance = scaled.clone() * pow10(-12);
+
+		let lower = decade
+			.iter()
+			.rev()
+			.map(|mantissa| BFloat::from(*mantissa))
+			.find(|mantissa| *mantissa <= scaled.clone() + tolerance.clone())
+			.unwrap_or(low);
+		let upper = decade
+			.iter()
+			.map(|mantissa| BFloat::from(*mantissa))
+			.find(|mantissa| *mantissa >= scaled.clone() - tolerance.clone())
+			.unwrap_or(high);
+
+		let scale = pow10(exponent);
+
+		Ok((lower * scale.clone(), upper * scale))
+	}
+
+	/// Every preferred value from `low` to `high`, both included, ascending.
+	pub fn values_between(&self, low: &BFloat, high: &BFloat) -> Result<Vec<BFloat>, CalcError> {
+		let (_, first) = self.scale_into_decade(low)?;
+		let (_, last) = self.scale_into_decade(high)?;
+
+		//INFO: the bounds snapped inwards, built the same way as the values so they compare exactly
+		let low = self.neighbours(low)?.1;
+		let high = self.neighbours(high)?.0;
+
+		Ok((first..=last)
+			.flat_map(|exponent| {
+				let scale = pow10(exponent);
+				self.decade().into_iter().map(move |mantissa| BFloat::from(mantissa) * scale.clone())
+			})
+			.filter(|value| *value >= low && *value <= high)
+			.collect())
+	}
+
+	//INFO: the value as scale * 10^exponent, with scale inside the decade of the table
+	fn scale_into_decade(&self, value: &BFloat) -> Result<(BFloat, i32), CalcError> {
+		if *value <= BFloat::zero() || value.0.is_nan() || value.0.is_inf() {
+			return Err(CalcError::NotPositive);
+		}
+
+		let (table, _, digits) = self.table();
+
+		let low = BFloat::from(table[0]);
+		let high = low.clone() * BFloat::from(10);
+
+		//INFO: the f64 guess is fixed up exactly
+		let guess = value.to_f64().log10();
+		let guess = if guess.is_finite() { guess.floor() as i32 } else { 0 };
+
+		let mut exponent = guess - (digits - 1);
+		let mut scaled = value.clone() / pow10(exponent);
+
+		while scaled < low {
+			exponent -= 1;
+			scaled = value.clone() / pow10(exponent);
+		}
+		while scaled >= high {
+			exponent += 1;
+			scaled = value.clone() / pow10(exponent);
+		}
+
+		Ok((scaled, exponent))
+	}
+}
+
+/// Relative change from `target` to `actual`, 0.01 is 1 % above.
+pub fn relative_error(actual: &BFloat, target: &BFloat) -> BFloat {
+	actual.clone() / target.clone() - BFloat::one()
+}
@@ -0,0 +1,216 @@
+//! Turning [`BFloat`]s into text. Digits come straight from the decimal expansion of the
+//! value and are rounded as text, nothing goes through `f64`.
+
+use std::{fmt::Display, str::FromStr};
+
+use astro_float::{Radix, RoundingMode, Sign};
+
+use crate::{error::CalcError, precision::with_consts, types::BFloat};
+
+/// How a result is written out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumberStyle {
+	/// `n` significant figures, positional unless the exponent is far from zero.
+	Significant(usize),
+	/// A fixed number of digits after the decimal point.
+	Decimals(usize),
+	/// `d.ddde-x` with `n` significant figures.
+	Scientific(usize),
+	/// Like [`NumberStyle::Scientific`], with the exponent a multiple of three.
+	Engineering(usize),
+	/// Every digit the value holds, no exponent.
+	Plain,
+}
+
+/// Styles offered next to the outputs, as accepted by [`NumberStyle::from_str`].
+pub const STYLE_PRESETS: [&str; 11] = [
+	"4 sig", "6 sig", "10 sig", "20 sig",
+	"2 dp", "4 dp", "6 dp",
+	"sci 6", "eng 4", "eng 6",
+	"plain",
+];
+
+//INFO: significant figures fall back to scientific outside of 1e-6..1e15
+const POSITIONAL_EXPONENTS: std::ops::Range<i64> = -6..15;
+
+impl Default for NumberStyle {
+	fn default() -> Self {
+		NumberStyle::Significant(10)
+	}
+}
+
+//INFO: "6 sig", "4 dp", "sci 6", "eng 6", "plain"
+impl FromStr for NumberStyle {
+	type Err = CalcError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let unknown = || CalcError::UnknownFormat(s.to_string());
+		let words = s.split_whitespace().collect::<Vec<&str>>();
+
+		let digits = |text: &str| text.parse::<usize>().map_err(|_| unknown());
+
+		match words.as_slice() {
+			["plain"] => Ok(NumberStyle::Plain),
+			[count, "sig"] => Ok(NumberStyle::Significant(digits(count)?.max(1))),
+			[count, "dp"] => Ok(NumberStyle::Decimals(digits(count)?)),
+			["sci", count] => Ok(NumberStyle::Scientific(digits(count)?.max(1))),
+			["eng", count] => Ok(NumberStyle::Engineering(digits(count)?.max(1))),
+			_ => Err(unknown()),
+		}
+	}
+}
+
+impl Display for NumberStyle {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			NumberStyle::Significant(count) => write!(f, "{count} sig"),
+			NumberStyle::Decimals(count) => write!(f, "{count} dp"),
+			NumberStyle::Scientific(count) => write!(f, "sci {count}"),
+			NumberStyle::Engineering(count) => write!(f, "eng {count}"),
+			NumberStyle::Plain => write!(f, "plain"),
+		}
+	}
+}
+
+/// Writes `value` in `style`. NaN and infinities are written as `NaN`, `Inf` and `-Inf`.
+pub fn format_number(value: &BFloat, style: NumberStyle) -> Result<String, astro_float::Error> {
+	if value.0.is_nan() { return Ok("NaN".to_string()); }
+	if value.0.is_inf_pos() { return Ok("Inf".to_string()); }
+	if value.0.is_inf_neg() { return Ok("-Inf".to_string()); }
+
+	let decimal = Decimal::from_bfloat(value)?;
+
+	Ok(match style {
+		NumberStyle::Significant(count) => {
+			let rounded = decimal.round_to(count as i64);
+
+			if rounded.is_zero() {
+				return Ok("0".to_string());
+			}
+
+			match POSITIONAL_EXPONENTS.contains(&(rounded.exponent - 1)) {
+				true => rounded.positional((count as i64 - rounded.exponent).max(0) as usize),
+				false => rounded.scientific(count, 1),
+			}
+		}
+		NumberStyle::Decimals(count) => decimal.round_to(decimal.exponent + count as i64).positional(count),
+		NumberStyle::Scientific(count) => decimal.round_to(count as i64).scientific(count, 1),
+		NumberStyle::Engineering(count) => decimal.round_to(count as i64).scientific(count, 3),
+		NumberStyle::Plain => decimal.positional((decimal.digits.len() as i64 - decimal.exponent).max(0) as usize),
+	})
+}
+
+//INFO: value = 0.DIGITS × 10^exponent, no leading or trailing zero digits, no digits for zero
+#[derive(Clone, Debug)]
+struct Decimal {
+	negative: bool,
+	digits: Vec<u8>,
+	exponent: i64,
+}
+
+impl Decimal {
+	fn from_bfloat(value: &BFloat) -> Result<Decimal, astro_float::Error> {
+		if value.0.is_zero() {
+			return Ok(Decimal { negative: false, digits: Vec::new(), exponent: 0 });
+		}
+
+		let (sign, mut digits, exponent) = with_consts(|cc| value.0.convert_to_radix(Radix::Dec, RoundingMode::None, cc))?;
+
+		let leading = digits.iter().take_while(|digit| **digit == 0).count();
+		digits.drain(..leading);
+
+		while digits.last() == Some(&0) {
+			digits.pop();
+		}
+
+		Ok(Decimal { negative: sign == Sign::Neg, digits, exponent: exponent as i64 - leading as i64 })
+	}
+
+	fn is_zero(&self) -> bool {
+		self.digits.is_empty()
+	}
+
+	fn digit(&self, index: i64) -> u8 {
+		match usize::try_from(index) {
+			Ok(index) => self.digits.get(index).copied().unwrap_or(0),
+			Err(_) => 0,
+		}
+	}
+
+	//INFO: keeps the first `count` digits, halves round away from zero like on paper
+	fn round_to(&self, count: i64) -> Decimal {
+		if count >= self.digits.len() as i64 {
+			return self.clone();
+		}
+		if count < 0 {
+			return Decimal { negative: false, digits: Vec::new(), exponent: 0 };
+		}
+
+		let mut digits = self.digits[..count as usize].to_vec();
+		let mut exponent = self.exponent;
+
+		if self.digits[count as usize] >= 5 {
+			let mut index = digits.len();
+
+			loop {
+				if index == 0 {
+					digits.insert(0, 1);
+					exponent += 1;
+					break;
+				}
+
+				index -= 1;
+
+				if digits[index] == 9 {
+					digits[index] = 0;
+				} else {
+					digits[index] += 1;
+					break;
+				}
+			}
+		}
+
+		while digits.last() == Some(&0) {
+			digits.pop();
+		}
+
+		Decimal { negative: self.negative && !digits.is_empty(), digits, exponent }
+	}
+
+	fn sign(&self) -> &'static str {
+		match self.negative {
+			true => "-",
+			false => "",
+		}
+	}
+
+	//INFO: "123.4500" for decimals = 4
+	fn positional(&self, decimals: usize) -> String {
+		let mut text = self.sign().to_string();
+
+		match self.exponent <= 0 {
+			true => text.push('0'),
+			false => (0..self.exponent).for_each(|index| text.push((b'0' + self.digit(index)) as char)),
+		}
+
+		if decimals > 0 {
+			text.push('.');
+			(0..decimals as i64).for_each(|index| text.push((b'0' + self.digit(self.exponent + index)) as char));
+		}
+
+		text
+	}
+
+	//INFO: mantissa with `count` digits, exponent a multiple of `step`, "4.70e-9" or "47.0e-9"
+	fn scientific(&self, count: usize, step: i64) -> String {
+		if self.is_zero() {
+			return Decimal { exponent: 1, ..self.clone() }.positional(count.saturating_sub(1));
+		}
+
+		let exponent = (self.exponent - 1).div_euclid(step) * step;
+		let mantissa = Decimal { exponent: self.exponent - exponent, ..self.clone() };
+
+		let decimals = (count as i64 - mantissa.exponent).max(0) as usize;
+
+		format!("{}e{exponent}", mantissa.positional(decimals))
+	}
+}
@@ -0,0 +1,465 @@
+//! GUI-free calculation core of The Calcularoty.
+//!
+//! Values are [`BFloat`]s, arbitrary precision floats, always in the base unit of their
+//! [`UnitType`] once they reach [`calculations`]. The [`units`] tables and [`conversions`]
+//! move values between prefixes such as `mH` and `H`.
+
+pub mod types;
+pub mod complex;
+pub mod error;
+pub mod consts;
+pub mod precision;
+pub mod units;
+pub mod conversions;
+pub mod parsing;
+pub mod calculations;
+pub mod network;
+pub mod sweep;
+pub mod eseries;
+pub mod combinations;
+pub mod montecarlo;
+pub mod worstcase;
+pub mod coil;
+pub mod cores;
+pub mod capacitor;
+pub mod utils;
+pub mod formatting;
+
+pub use types::{BFloat, UnitType};
+pub use complex::{ComplexBFloat, ComplexExt};
+pub use error::CalcError;
+pub use formatting::NumberStyle;
+
+#[cfg(test)]
+mod tests {
+	use num_traits::{Zero, One};
+
+use crate::types::BFloat;
+
+	fn f(v: f64) -> BFloat {
+        BFloat::from(v) // convenience: convert f64 → MyFloat
+    }
+
+    #[test]
+    fn test_basic_arithmetic() {
+        let two = f(2.0);
+        let three = f(3.0);
+
+        assert_eq!(two.clone() + three.clone(), f(5.0));
+        assert_eq!(two.clone() * three.clone(), f(6.0));
+        assert_eq!(three.clone() - two.clone(), f(1.0));
+    }
+
+	#[test]
+	fn zero_functions() {
+		let zero = f(0.0);
+		let mut one = f(1.0);
+		
+		assert!(zero.is_zero());
+		assert!(!one.is_zero());
+
+		one.set_zero();
+
+		assert!(one.is_zero());
+	}
+
+	#[test]
+	fn one_functions() {
+		let mut zero = f(0.0);
+		let one = f(1.0);
+		
+		assert!(!zero.is_one());
+		assert!(one.is_one());
+
+		zero.set_one();
+
+		assert!(zero.is_one());
+	}
+
+	#[test]
+	fn series_rlc_at_resonance_is_resistive() {
+		use crate::calculations::{calculate_impedance_series, calculate_resonant_frequency};
+		use crate::conversions::get_omega;
+
+		let r = f(50.0);
+		let l = f(0.01);
+		let c = f(1e-6);
+
+		let omega = get_omega(calculate_resonant_frequency(l.clone(), c.clone()));
+		let result = calculate_impedance_series(Some(r.clone()), l, c, omega);
+
+		assert!((result.magnitude() - r).abs() < f(1e-20));
+		assert!(result.phase().abs() < f(1e-20));
+	}
+
+	#[test]
+	fn half_power_frequencies_are_geometric_around_f0() {
+		use crate::calculations::calculate_resonance_parallel;
+
+		let resonance = calculate_resonance_parallel(Some(f(1000.0)), f(0.01), f(1e-6));
+
+		let product = resonance.lower_frequency.clone() * resonance.upper_frequency.clone();
+		let f0_squared = resonance.resonant_frequency.clone() * resonance.resonant_frequency.clone();
+
+		assert!((product - f0_squared).abs() < f(1e-20));
+		assert!((resonance.upper_frequency - resonance.lower_frequency - resonance.bandwidth).abs() < f(1e-20));
+	}
+
+	#[test]
+	fn bad_input_is_an_error() {
+		use crate::conversions::convert_measure;
+		use crate::{CalcError, UnitType};
+
+		assert_eq!("".parse::<BFloat>(), Err(CalcError::EmptyInput));
+		assert_eq!("abc".parse::<BFloat>(), Err(CalcError::InvalidNumber("abc".to_string())));
+		assert_eq!(convert_measure(f(1.0), &UnitType::Farad, "μF", "kHz"), Err(CalcError::UnknownUnit("kHz".to_string())));
+		assert_eq!(convert_measure(f(1.0), &UnitType::NotSelected, "", ""), Err(CalcError::UnitNotSelected));
+	}
+
+	#[test]
+	fn engineering_notation() {
+		use crate::conversions::parse_to_base;
+		use crate::{CalcError, UnitType};
+
+		let close = |a: BFloat, b: f64| (a - f(b)).abs() < f(b.abs() * 1e-15);
+
+		assert!(close("4.7u".parse().unwrap(), 4.7e-6));
+		assert!(close("4u7".parse().unwrap(), 4.7e-6));
+		assert!(close("4R7".parse().unwrap(), 4.7));
+		assert!(close("100p".parse().unwrap(), 100e-12));
+		assert!(close("1e-6".parse().unwrap(), 1e-6));
+
+		assert!(close(parse_to_base("10 nF", &UnitType::Farad, "μF").unwrap(), 10e-9));
+		assert!(close(parse_to_base("2.2kΩ", &UnitType::Ohm, "Ω").unwrap(), 2200.0));
+		assert!(close(parse_to_base("10", &UnitType::Farad, "nF").unwrap(), 10e-9));
+		assert!(close(parse_to_base("3 MHz", &UnitType::Hertz, "Hz").unwrap(), 3e6));
+
+		assert_eq!(parse_to_base("10 nF", &UnitType::Henry, "mH"), Err(CalcError::UnknownUnit("F".to_string())));
+
+		//INFO: a trailing m is the meter in a size field and milli anywhere else
+		assert!(close(parse_to_base("10mm", &UnitType::Meter, "m").unwrap(), 10e-3));
+		assert!(close(parse_to_base("2m", &UnitType::Meter, "mm").unwrap(), 2.0));
+		assert!(close(parse_to_base("0.5 mm", &UnitType::Meter, "m").unwrap(), 0.5e-3));
+		assert!(close(parse_to_base("3cm", &UnitType::Meter, "m").unwrap(), 3e-2));
+		assert!(close(parse_to_base("10m", &UnitType::Henry, "H").unwrap(), 10e-3));
+		assert!(parse_to_base("10mm", &UnitType::Henry, "H").is_err());
+		assert!("3c".parse::<BFloat>().is_err());
+
+		//INFO: the prefix of an area is squared with its length, a bare one is refused
+		assert!(close(parse_to_base("10mm²", &UnitType::SquareMeter, "m²").unwrap(), 10e-6));
+		assert!(close(parse_to_base("4 cm²", &UnitType::SquareMeter, "m²").unwrap(), 4e-4));
+		assert!(close(parse_to_base("2m²", &UnitType::SquareMeter, "mm²").unwrap(), 2.0));
+		assert!(close(parse_to_base("100", &UnitType::SquareMeter, "mm²").unwrap(), 100e-6));
+		assert_eq!(parse_to_base("10m", &UnitType::SquareMeter, "m²"), Err(CalcError::UnknownUnit("m".to_string())));
+		assert_eq!(parse_to_base("10k", &UnitType::SquareMeter, "m²"), Err(CalcError::AreaPrefix));
+		assert_eq!("4.7x".parse::<BFloat>(), Err(CalcError::InvalidNumber("4.7x".to_string())));
+	}
+
+	#[test]
+	fn auto_output_picks_engineering_prefix() {
+		use crate::conversions::convert_for_output;
+		use crate::UnitType;
+
+		let (value, label) = convert_for_output(f(1591.5), &UnitType::Hertz, "Auto").unwrap();
+		assert_eq!(label, Some("kHz"));
+		assert!((value - f(1.5915)).abs() < f(1e-12));
+
+		let (_, label) = convert_for_output(f(4.7e-9), &UnitType::Farad, "Auto F").unwrap();
+		assert_eq!(label, Some("nF"));
+
+		let (_, label) = convert_for_output(f(0.0), &UnitType::Ohm, "Auto").unwrap();
+		assert_eq!(label, Some("Ω"));
+
+		let (_, label) = convert_for_output(f(1.0), &UnitType::Ohm, "kΩ").unwrap();
+		assert_eq!(label, None);
+	}
+
+	#[test]
+	fn precision_settings() {
+		use crate::precision::{precision, set_precision, set_rounding_mode, significant_digits};
+
+		assert_eq!(significant_digits(1024), 308);
+		assert_eq!(significant_digits(128), 38);
+		assert!(!set_rounding_mode("Sideways"));
+
+		//INFO: typed values are read at the working precision, not at the 64 bits of a plain parse
+		let bits = precision();
+		set_precision(512);
+		let tenth = BFloat::from(1) / BFloat::from(10);
+		assert!(("0.1".parse::<BFloat>().unwrap() - tenth.clone()).abs() < "1e-150".parse().unwrap());
+		assert!(("100m".parse::<BFloat>().unwrap() - tenth).abs() < "1e-150".parse().unwrap());
+		set_precision(bits);
+	}
+
+	#[test]
+	fn number_styles() {
+		use crate::formatting::{format_number, NumberStyle};
+
+		let show = |value: &str, style: &str| {
+			format_number(&value.parse::<BFloat>().unwrap(), style.parse::<NumberStyle>().unwrap()).unwrap()
+		};
+
+		assert_eq!(show("1591.549", "6 sig"), "1591.55");
+		assert_eq!(show("0.0047", "4 sig"), "0.004700");
+		assert_eq!(show("9.9996", "4 sig"), "10.00");
+		assert_eq!(show("1e20", "6 sig"), "1.00000e20");
+		assert_eq!(show("0", "6 sig"), "0");
+		assert_eq!(show("-0.006", "2 dp"), "-0.01");
+		assert_eq!(show("0.004", "2 dp"), "0.00");
+		assert_eq!(show("123456", "sci 3"), "1.23e5");
+		assert_eq!(show("4.7e-9", "eng 4"), "4.700e-9");
+		assert_eq!(show("47e-9", "eng 3"), "47.0e-9");
+		assert_eq!(show("0.5", "plain"), "0.5");
+
+		assert!("7 bananas".parse::<NumberStyle>().is_err());
+	}
+
+	#[test]
+	fn complex_arithmetic() {
+		use crate::complex::{ComplexBFloat, ComplexExt};
+
+		let z = |re: f64, im: f64| ComplexBFloat::new(f(re), f(im));
+		let close = |a: &ComplexBFloat, b: &ComplexBFloat| (a.clone() - b.clone()).magnitude() < f(1e-30);
+
+		assert_eq!(z(3.0, 4.0).magnitude(), f(5.0));
+		assert!(close(&(z(1.0, 1.0) / z(1.0, -1.0)), &z(0.0, 1.0)));
+		assert!(close(&(z(2.0, 3.0) * z(2.0, 3.0).conj()), &z(13.0, 0.0)));
+
+		let (r, theta) = z(-1.0, 1.0).to_polar();
+		assert!(close(&ComplexBFloat::from_polar(r, theta), &z(-1.0, 1.0)));
+
+		assert_eq!(f(7.5) % f(2.0), f(1.5));
+	}
+
+	#[test]
+	fn network_notation() {
+		use crate::complex::ComplexExt;
+		use crate::network::Network;
+
+		let network: Network = "50Ω + (10mH | 100nF)".parse().unwrap();
+		assert_eq!(network.element_count(), 3);
+		assert!(matches!(&network, Network::Series(parts) if matches!(parts[1], Network::Parallel(_))));
+
+		//INFO: the L∥C tank is purely reactive, the real part is the resistor alone
+		let omega = f(1.0);
+		let expected = f(50.0);
+		let z = network.impedance(&omega);
+		assert!((z.re - expected).abs() < f(1e-20));
+
+		let series: Network = "1kΩ + 1kΩ + 2k2Ω".parse().unwrap();
+		assert!((series.impedance(&omega).magnitude() - f(4200.0)).abs() < f(1e-20));
+
+		assert!("50 + 10mH".parse::<Network>().is_err());
+		assert!("(50Ω + 10mH".parse::<Network>().is_err());
+		assert!("50Ω ++ 10mH".parse::<Network>().is_err());
+	}
+
+	#[test]
+	fn frequency_sweep() {
+		use crate::sweep::{sweep_frequencies, sweep_rlc, SweepScale};
+
+		let close = |a: &BFloat, b: f64| (a.clone() - f(b)).abs() < f(1e-20);
+
+		let linear = sweep_frequencies(f(100.0), f(200.0), 5, SweepScale::Linear).unwrap();
+		assert!(linear.iter().zip([100.0, 125.0, 150.0, 175.0, 200.0]).all(|(a, b)| close(a, b)));
+
+		let log = sweep_frequencies(f(10.0), f(10000.0), 4, SweepScale::Logarithmic).unwrap();
+		assert!(log.iter().zip([10.0, 100.0, 1000.0, 10000.0]).all(|(a, b)| close(a, b)));
+
+		//INFO: a series LC with no resistor dips to 0 Ω at f0 and is capacitive below it
+		let (l, c) = (f(1.0), f(1.0));
+		let f0 = crate::calculations::lc_to_f0(l.clone(), c.clone());
+		let points = sweep_rlc(None, l, c, false, &[f0.clone() / f(2.0), f0]);
+		assert!(points[0].phase < f(0.0));
+		assert!(points[1].impedance < f(1e-20));
+
+		assert!(sweep_frequencies(f(0.0), f(10.0), 5, SweepScale::Logarithmic).is_err());
+		assert!(sweep_frequencies(f(1.0), f(10.0), 1, SweepScale::Linear).is_err());
+		assert!("sideways".parse::<SweepScale>().is_err());
+	}
+
+	#[test]
+	fn lossy_f64_for_plots() {
+		assert_eq!(f(0.0).to_f64(), 0.0);
+		assert_eq!(f(-2.5).to_f64(), -2.5);
+		assert_eq!(f(1e-12).to_f64(), 1e-12);
+		assert!(BFloat::nan().to_f64().is_nan());
+	}
+
+	#[test]
+	fn e_series_neighbours() {
+		use crate::eseries::ESeries;
+
+		assert_eq!(ESeries::E6.decade(), vec![10, 15, 22, 33, 47, 68]);
+		assert_eq!(ESeries::E96.decade().len(), 96);
+
+		let close = |a: &BFloat, b: f64| (a.clone() - f(b)).abs() < f(b * 1e-12);
+
+		let (lower, upper) = ESeries::E12.neighbours(&f(5.0e-3)).unwrap();
+		assert!(close(&lower, 4.7e-3) && close(&upper, 5.6e-3));
+
+		//INFO: past the last value of a decade the upper neighbour is the first of the next
+		let (lower, upper) = ESeries::E24.neighbours(&f(95.0)).unwrap();
+		assert!(close(&lower, 91.0) && close(&upper, 100.0));
+
+		let (lower, upper) = ESeries::E192.neighbours(&f(920e-9)).unwrap();
+		assert!(close(&lower, 920e-9) && close(&upper, 920e-9));
+
+		//INFO: a value just off a preferred one, but past the snap, still lies between two of them
+		let (lower, upper) = ESeries::E192.neighbours(&f(920.0001e-9)).unwrap();
+		assert!(close(&lower, 920e-9) && close(&upper, 931e-9));
+		let (lower, upper) = ESeries::E192.neighbours(&f(919.9999e-9)).unwrap();
+		assert!(close(&lower, 909e-9) && close(&upper, 920e-9));
+
+		assert!(ESeries::E6.neighbours(&f(0.0)).is_err());
+		assert!("E7".parse::<ESeries>().is_err());
+	}
+
+	#[test]
+	fn standard_value_pairs() {
+		use crate::combinations::{best_pairs, combine, Connection};
+		use crate::eseries::ESeries;
+		use crate::types::UnitType;
+
+		assert_eq!(combine(&f(10.0), &f(10.0), UnitType::Farad, Connection::Series).unwrap(), f(5.0));
+		assert_eq!(combine(&f(10.0), &f(10.0), UnitType::Henry, Connection::Series).unwrap(), f(20.0));
+
+		//INFO: 5 μH is no E12 value but 2.2 μH + 2.7 μH and 10 μH ∥ 10 μH come close or hit it
+		let pairs = best_pairs(&f(5e-6), UnitType::Henry, ESeries::E12, None, 5).unwrap();
+		assert_eq!(pairs.len(), 5);
+		assert!(pairs.windows(2).all(|pair| pair[0].error.abs() <= pair[1].error.abs()));
+		assert!(pairs[0].error.abs() < f(1e-12));
+
+		let tolerance = f(0.001);
+		let close = best_pairs(&f(5e-6), UnitType::Henry, ESeries::E12, Some(&tolerance), 50).unwrap();
+		assert!(close.iter().all(|pair| pair.error.abs() <= tolerance));
+
+		assert!(best_pairs(&f(1.0), UnitType::Hertz, ESeries::E12, None, 5).is_err());
+	}
+
+	#[test]
+	fn monte_carlo_statistics() {
+		use crate::montecarlo::{monte_carlo, statistics, Distribution, Rng, Toleranced};
+
+		let summary = statistics(&[f(1.0), f(2.0), f(3.0), f(4.0)], 4);
+		assert_eq!((summary.min, summary.max, summary.mean), (f(1.0), f(4.0), f(2.5)));
+		assert!((summary.std_dev - (f(5.0) / f(3.0)).sqrt()).abs() < f(1e-30));
+		assert_eq!(summary.histogram, vec![1, 1, 1, 1]);
+
+		//INFO: a ±10 % part stays in its band whatever the distribution, f(0.1) would be a hair above 10 %
+		let part = Toleranced::new(f(100.0), BFloat::one() / BFloat::from(10));
+		for distribution in [Distribution::Uniform, Distribution::Normal] {
+			let runs = monte_carlo(std::slice::from_ref(&part), distribution, 500, &mut Rng::new(7), |values| Ok(values.to_vec())).unwrap();
+			assert!(runs[0].min >= part.at(&-BFloat::one()) && runs[0].max <= part.at(&BFloat::one()));
+			assert!((runs[0].mean.clone() - f(100.0)).abs() < f(2.0));
+			assert_eq!(runs[0].histogram.iter().sum::<usize>(), 500);
+		}
+
+		assert!(monte_carlo(&[part], Distribution::Uniform, 1, &mut Rng::new(7), |values| Ok(values.to_vec())).is_err());
+	}
+
+	#[test]
+	fn worst_case_corners() {
+		use crate::calculations::calculate_resonant_frequency;
+		use crate::montecarlo::Toleranced;
+		use crate::worstcase::{sensitivities, worst_case};
+
+		let f0 = |values: &[BFloat]| Ok(vec![calculate_resonant_frequency(values[0].clone(), values[1].clone())]);
+
+		//INFO: f0 goes with 1/√(LC), both parts weigh -1/2
+		let sensitivity = sensitivities(&[f(1e-3), f(1e-6)], f0).unwrap();
+		for value in &sensitivity[0] {
+			assert!((value.clone() + f(0.5)).abs() < f(1e-20));
+		}
+
+		let parts = [Toleranced::new(f(1e-3), f(0.1)), Toleranced::new(f(1e-6), f(0.1))];
+		let extremes = &worst_case(&parts, f0).unwrap()[0];
+
+		let corner = |edge: f64| calculate_resonant_frequency(parts[0].at(&f(edge)), parts[1].at(&f(edge)));
+		assert_eq!((extremes.min.clone(), extremes.max.clone()), (corner(1.0), corner(-1.0)));
+		assert_eq!((extremes.min_corner.clone(), extremes.max_corner.clone()), (vec![1, 1], vec![-1, -1]));
+	}
+
+	#[test]
+	fn air_core_coils() {
+		use crate::coil::{nagaoka, CoilGeometry, CoilShape};
+
+		//INFO: Nagaoka's table: 1 for an endless solenoid, 0.6884 as long as it is wide
+		assert!((nagaoka(&f(1.0), &f(1e9)) - f(1.0)).abs() < f(1e-8));
+		assert!((nagaoka(&f(1.0), &f(1.0)) - f(0.6884)).abs() < f(1e-4));
+
+		for shape in [CoilShape::SingleLayer, CoilShape::MultiLayer, CoilShape::FlatSpiral] {
+			let geometry = CoilGeometry { shape, diameter: f(0.02), length: Some(f(0.03)), wire: f(0.0005) };
+
+			let coil = geometry.coil(&f(40.0)).unwrap();
+			let turns = geometry.turns_for(&coil.inductance).unwrap();
+
+			assert!((turns - f(40.0)).abs() < f(1e-20), "{shape}");
+		}
+
+		let close_wound = CoilGeometry { shape: CoilShape::SingleLayer, diameter: f(0.01), length: None, wire: f(0.001) };
+		assert_eq!(close_wound.coil(&f(25.0)).unwrap().length, f(25.0) * f(0.001));
+
+		let short = CoilGeometry { length: Some(f(0.01)), ..close_wound };
+		assert!(short.coil(&f(25.0)).is_err());
+	}
+
+	#[test]
+	fn core_catalog() {
+		use crate::conversions::pow10;
+		use crate::cores::{al_inductance, al_turns, parse_catalog, toroid_al, BUNDLED_CATALOG};
+
+		let cores = parse_catalog(BUNDLED_CATALOG).unwrap();
+		let t50 = cores.iter().find(|core| core.name == "T50-2").unwrap();
+		assert!((t50.al.clone() - f(4.9e-9)).abs() < f(1e-20));
+
+		//INFO: the geometry of a T50-2 gives about its listed 4.9 nH
+		let al = toroid_al(&t50.permeability, &t50.outer_diameter, &t50.inner_diameter, &t50.height).unwrap();
+		assert!((al.clone() - t50.al.clone()).abs() < f(0.2e-9));
+		assert!((parse_catalog("T; toroid; -; 10; 12.7; 7.7; 4.83").unwrap()[0].al.clone() - al).abs() < f(1e-20));
+
+		let inductance = al_inductance(&t50.al, &f(40.0)).unwrap();
+		assert!((al_turns(&t50.al, &inductance).unwrap() - f(40.0)).abs() < f(1e-20));
+
+		//INFO: sizes with a unit, a trailing m is the meter as in any size field
+		let exact = |digits: i32, exponent: i32| BFloat::from(digits) * pow10(exponent);
+		let sized = parse_catalog("T; toroid; 4.9nH; 10; 12.7mm; 7.7mm; 0.483cm").unwrap();
+		assert!((sized[0].outer_diameter.clone() - exact(127, -4)).abs() < f(1e-20));
+		assert!((sized[0].height.clone() - exact(483, -5)).abs() < f(1e-20));
+		assert!((parse_catalog("T; toroid; 4.9; 10; 12.7m; 7.7; 4.83").unwrap()[0].outer_diameter.clone() - exact(127, -1)).abs() < f(1e-20));
+
+		assert!(parse_catalog("T; toroid; 4.9; 10; 12.7").is_err());
+		assert!(parse_catalog("P; pot; -; 2000; 14.3; 3; 8.4").is_err());
+		assert!(parse_catalog("A; toroid; 1; 1; 2; 1; 1\nA; toroid; 1; 1; 2; 1; 1").is_err());
+	}
+
+	#[test]
+	fn capacitor_geometry() {
+		use crate::capacitor::*;
+		use crate::consts::{epsilon0, z0_free_space};
+
+		assert!((epsilon0() - f(8.8541878e-12)).abs() < f(1e-19));
+		assert!((z0_free_space() - f(376.730313)).abs() < f(1e-6));
+
+		//INFO: 1 cm² 1 mm apart in air is a textbook 0.885 pF
+		let area = f(1e-4);
+		let separation = f(1e-3);
+		let vacuum = dielectric("vacuum").unwrap();
+		let c = plate_capacitance(&area, &separation, &vacuum).unwrap();
+		assert!((c.clone() - f(0.8854e-12)).abs() < f(1e-16));
+		assert!((plate_separation(&area, &vacuum, &c).unwrap() - separation.clone()).abs() < f(1e-30));
+		assert!((plate_area(&separation, &vacuum, &c).unwrap() - area).abs() < f(1e-30));
+
+		//INFO: RG-58, 0.9 mm in 2.95 mm polyethylene, is about 100 pF/m and 50 Ω
+		let (inner, outer) = (f(0.9e-3), f(2.95e-3));
+		let polyethylene = dielectric("Polyethylene").unwrap();
+		let c = coax_capacitance(&inner, &outer, &f(1.0), &polyethylene).unwrap();
+		assert!((c.clone() - f(105e-12)).abs() < f(5e-12));
+		assert!((coax_impedance(&inner, &outer, &polyethylene).unwrap() - f(47.5)).abs() < f(1.0));
+		assert!((coax_length(&inner, &outer, &polyethylene, &c).unwrap() - f(1.0)).abs() < f(1e-30));
+		assert!((coax_outer_diameter(&inner, &f(1.0), &polyethylene, &c).unwrap() - outer.clone()).abs() < f(1e-30));
+
+		assert!(coax_capacitance(&outer, &inner, &f(1.0), &polyethylene).is_err());
+		assert!(dielectric("unobtainium").is_none());
+	}
+}
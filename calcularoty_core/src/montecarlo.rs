@@ -0,0 +1,187 @@
+//! Tolerance analysis by Monte Carlo: every part is drawn inside its tolerance band, the circuit
+//! is worked out for each draw and the results are summed up as [`Statistics`].
+//!
+//! The random deviates are `f64`, they only pick where in the band a part lands, everything
+//! after that is [`BFloat`].
+
+use std::{fmt::Display, str::FromStr, time::{SystemTime, UNIX_EPOCH}};
+
+use num_traits::{One, Zero};
+
+use crate::error::CalcError;
+use crate::types::BFloat;
+
+/// Most samples a run may take, each one is a full precision evaluation.
+pub const MAX_SAMPLES: usize = 100_000;
+
+/// Bars in the histogram of each result.
+pub const HISTOGRAM_BINS: usize = 20;
+
+/// Names of the distributions, in the order [`Distribution::from_str`] knows them.
+pub const DISTRIBUTIONS: [&str; 2] = ["Uniform", "Normal"];
+
+/// How part values spread inside their tolerance band.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Distribution {
+	/// Anywhere in the band, equally likely.
+	#[default]
+	Uniform,
+	/// Gaussian with the band edge at 3σ, draws past the edge are clipped to it.
+	Normal,
+}
+
+impl FromStr for Distribution {
+	type Err = CalcError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim().to_lowercase().as_str() {
+			"uniform" => Ok(Distribution::Uniform),
+			"normal" | "gaussian" => Ok(Distribution::Normal),
+			_ => Err(CalcError::InvalidAnalysis(format!("'{s}' is not a distribution, Uniform or Normal"))),
+		}
+	}
+}
+
+impl Display for Distribution {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Distribution::Uniform => write!(f, "{}", DISTRIBUTIONS[0]),
+			Distribution::Normal => write!(f, "{}", DISTRIBUTIONS[1]),
+		}
+	}
+}
+
+/// A part value with its relative tolerance, 0.05 for ±5 %.
+#[derive(Clone, Debug)]
+pub struct Toleranced {
+	pub nominal: BFloat,
+	pub tolerance: BFloat,
+}
+
+impl Toleranced {
+	pub fn new(nominal: BFloat, tolerance: BFloat) -> Toleranced {
+		Toleranced { nominal, tolerance }
+	}
+
+	/// The value `deviate` of the way to the band edge, -1 is the low edge and 1 the high one.
+	pub fn at(&self, deviate: &BFloat) -> BFloat {
+		self.nominal.clone() * (BFloat::one() + self.tolerance.clone() * deviate.clone())
+	}
+}
+
+/// SplitMix64, small and good enough to scatter parts, not for anything secret.
+pub struct Rng(u64);
+
+impl Rng {
+	pub fn new(seed: u64) -> Rng {
+		Rng(seed)
+	}
+
+	/// Seeded from the clock, a different run every time.
+	pub fn from_clock() -> Rng {
+		let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos() as u64);
+		Rng(nanos.unwrap_or(0x5EED))
+	}
+
+	fn next_u64(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+		z ^ (z >> 31)
+	}
+
+	//INFO: 53 random bits, uniform in 0..1
+	fn next_f64(&mut self) -> f64 {
+		(self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+	}
+
+	/// A position in the band, -1..=1, spread as `distribution`.
+	pub fn deviate(&mut self, distribution: Distribution) -> f64 {
+		match distribution {
+			Distribution::Uniform => 2.0 * self.next_f64() - 1.0,
+			Distribution::Normal => {
+				//INFO: Box-Muller, 1 - u keeps the logarithm away from 0
+				let radius = (-2.0 * (1.0 - self.next_f64()).ln()).sqrt();
+				let angle = std::f64::consts::TAU * self.next_f64();
+
+				(radius * angle.cos() / 3.0).clamp(-1.0, 1.0)
+			}
+		}
+	}
+}
+
+/// Spread of one result over a run.
+#[derive(Clone, Debug)]
+pub struct Statistics {
+	pub min: BFloat,
+	pub max: BFloat,
+	pub mean: BFloat,
+	/// Sample standard deviation σ.
+	pub std_dev: BFloat,
+	/// Counts of [`HISTOGRAM_BINS`] equal bins from `min` to `max`.
+	pub histogram: Vec<usize>,
+}
+
+/// Min, max, mean, σ and histogram of `values`, which must not be empty.
+pub fn statistics(values: &[BFloat], bins: usize) -> Statistics {
+	let count = BFloat::from(values.len() as u64);
+
+	let min = values.iter().fold(values[0].clone(), |min, value| if *value < min { value.clone() } else { min });
+	let max = values.iter().fold(values[0].clone(), |max, value| if *value > max { value.clone() } else { max });
+	let mean = values.iter().fold(BFloat::zero(), |sum, value| sum + value.clone()) / count.clone();
+
+	let std_dev = match values.len() {
+		1 => BFloat::zero(),
+		_ => {
+			let squares = values.iter().fold(BFloat::zero(), |sum, value| {
+				let offset = value.clone() - mean.clone();
+				sum + offset.clone() * offset
+			});
+			(squares / (count - BFloat::one())).sqrt()
+		}
+	};
+
+	//INFO: the bin only picks a bar, f64 is plenty, the top value goes in the last bar
+	let mut histogram = vec![0; bins.max(1)];
+	let (low, span) = (min.to_f64(), (max.clone() - min.clone()).to_f64());
+
+	for value in values {
+		let fraction = if span > 0.0 { (value.to_f64() - low) / span } else { 0.0 };
+		let bin = ((fraction * histogram.len() as f64) as usize).min(histogram.len() - 1);
+		histogram[bin] += 1;
+	}
+
+	Statistics { min, max, mean, std_dev, histogram }
+}
+
+/// Draws every part `samples` times and gathers the statistics of each value `evaluate` returns.
+pub fn monte_carlo(
+	parts: &[Toleranced],
+	distribution: Distribution,
+	samples: usize,
+	rng: &mut Rng,
+	evaluate: impl Fn(&[BFloat]) -> Result<Vec<BFloat>, CalcError>,
+) -> Result<Vec<Statistics>, CalcError> {
+	if !(2..=MAX_SAMPLES).contains(&samples) {
+		return Err(CalcError::InvalidAnalysis(format!("between 2 and {MAX_SAMPLES} samples are needed")));
+	}
+
+	let mut results: Vec<Vec<BFloat>> = Vec::new();
+
+	for _ in 0..samples {
+		let values = parts
+			.iter()
+			.map(|part| part.at(&BFloat::from(rng.deviate(distribution))))
+			.collect::<Vec<BFloat>>();
+
+		for (index, result) in evaluate(&values)?.into_iter().enumerate() {
+			match results.get_mut(index) {
+				Some(column) => column.push(result),
+				None => results.push(vec![result]),
+			}
+		}
+	}
+
+	Ok(results.iter().map(|column| statistics(column, HISTOGRAM_BINS)).collect())
+}
@@ -0,0 +1,231 @@
+//! Nested series/parallel networks of resistors, inductors and capacitors.
+//!
+//! Networks are written as text, `+` joins parts in series, `|` (or `∥`, `//`) in parallel and
+//! binds tighter than `+`, parentheses group. Every element is a value with its unit symbol,
+//! which tells what kind of part it is: `50Ω + (10mH | 100nF)`.
+
+use std::{fmt::Display, str::FromStr};
+
+use num_traits::Zero;
+
+use crate::calculations::{lc_capacitive_reactance, lc_inductive_reactance, resistor_impedance};
+use crate::complex::ComplexBFloat;
+use crate::consts::{FARAD_BASE_TYPE, HENRY_BASE_TYPE, OHM_BASE_TYPE};
+use crate::error::CalcError;
+use crate::parsing::EngineeringValue;
+use crate::types::BFloat;
+
+/// A part or a group of parts, values in base units.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Network {
+	Resistor(BFloat),
+	Inductor(BFloat),
+	Capacitor(BFloat),
+	/// Parts one after the other, an empty group is a short.
+	Series(Vec<Network>),
+	/// Parts side by side, an empty group is an open.
+	Parallel(Vec<Network>),
+}
+
+impl Network {
+	/// Complex impedance of the whole network at angular frequency `omega`.
+	pub fn impedance(&self, omega: &BFloat) -> ComplexBFloat {
+		match self {
+			Network::Resistor(r) => resistor_impedance(r.clone()),
+			Network::Inductor(l) => lc_inductive_reactance(l.clone(), omega.clone()),
+			Network::Capacitor(c) => lc_capacitive_reactance(c.clone(), omega.clone()),
+			Network::Series(parts) => parts
+				.iter()
+				.fold(ComplexBFloat::zero(), |sum, part| sum + part.impedance(omega)),
+			Network::Parallel(parts) => Network::admittance_of(parts, omega).inv(),
+		}
+	}
+
+	/// Complex admittance of the whole network at angular frequency `omega`.
+	pub fn admittance(&self, omega: &BFloat) -> ComplexBFloat {
+		match self {
+			Network::Parallel(parts) => Network::admittance_of(parts, omega),
+			_ => self.impedance(omega).inv(),
+		}
+	}
+
+	fn admittance_of(parts: &[Network], omega: &BFloat) -> ComplexBFloat {
+		parts
+			.iter()
+			.fold(ComplexBFloat::zero(), |sum, part| sum + part.admittance(omega))
+	}
+
+	/// Number of R, L and C elements in the network.
+	pub fn element_count(&self) -> usize {
+		match self {
+			Network::Series(parts) | Network::Parallel(parts) => parts.iter().map(Network::element_count).sum(),
+			_ => 1,
+		}
+	}
+}
+
+//INFO: writes the network back in the notation it is parsed from, values in base units
+impl Display for Network {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let join = |parts: &[Network], separator: &str| {
+			parts
+				.iter()
+				.map(|part| match part {
+					Network::Series(_) | Network::Parallel(_) => format!("({part})"),
+					_ => part.to_string(),
+				})
+				.collect::<Vec<String>>()
+				.join(separator)
+		};
+
+		match self {
+			Network::Resistor(r) => write!(f, "{r}{OHM_BASE_TYPE}"),
+			Network::Inductor(l) => write!(f, "{l}{HENRY_BASE_TYPE}"),
+			Network::Capacitor(c) => write!(f, "{c}{FARAD_BASE_TYPE}"),
+			Network::Series(parts) => write!(f, "{}", join(parts, " + ")),
+			Network::Parallel(parts) => write!(f, "{}", join(parts, " | ")),
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+	Series,
+	Parallel,
+	Open,
+	Close,
+	Element(String),
+}
+
+impl FromStr for Network {
+	type Err = CalcError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let tokens = tokenize(s);
+
+		if tokens.is_empty() {
+			return Err(CalcError::EmptyInput);
+		}
+
+		let mut position = 0;
+		let network = parse_series(&tokens, &mut position)?;
+
+		match tokens.get(position) {
+			None => Ok(network),
+			Some(_) => Err(CalcError::InvalidNetwork("unmatched ')'".to_string())),
+		}
+	}
+}
+
+fn tokenize(s: &str) -> Vec<Token> {
+	let mut tokens = Vec::new();
+	let mut element = String::new();
+	let mut chars = s.chars().peekable();
+
+	let flush = |element: &mut String, tokens: &mut Vec<Token>| {
+		if !element.trim().is_empty() {
+			tokens.push(Token::Element(element.trim().to_string()));
+		}
+		element.clear();
+	};
+
+	while let Some(c) = chars.next() {
+		//INFO: the sign of an exponent, "1e+3", is part of the value
+		let in_exponent = element.ends_with(['e', 'E']) && element.trim_end_matches(['e', 'E']).ends_with(|c: char| c.is_ascii_digit());
+
+		match c {
+			'+' if !in_exponent => {
+				flush(&mut element, &mut tokens);
+				tokens.push(Token::Series);
+			}
+			'|' | '∥' => {
+				flush(&mut element, &mut tokens);
+				tokens.push(Token::Parallel);
+			}
+			'/' if chars.peek() == Some(&'/') => {
+				chars.next();
+				flush(&mut element, &mut tokens);
+				tokens.push(Token::Parallel);
+			}
+			'(' => {
+				flush(&mut element, &mut tokens);
+				tokens.push(Token::Open);
+			}
+			')' => {
+				flush(&mut element, &mut tokens);
+				tokens.push(Token::Close);
+			}
+			_ => element.push(c),
+		}
+	}
+
+	flush(&mut element, &mut tokens);
+
+	tokens
+}
+
+//INFO: series := parallel ('+' parallel)*
+fn parse_series(tokens: &[Token], position: &mut usize) -> Result<Network, CalcError> {
+	let mut parts = vec![parse_parallel(tokens, position)?];
+
+	while tokens.get(*position) == Some(&Token::Series) {
+		*position += 1;
+		parts.push(parse_parallel(tokens, position)?);
+	}
+
+	Ok(group(parts, Network::Series))
+}
+
+//INFO: parallel := term ('|' term)*
+fn parse_parallel(tokens: &[Token], position: &mut usize) -> Result<Network, CalcError> {
+	let mut parts = vec![parse_term(tokens, position)?];
+
+	while tokens.get(*position) == Some(&Token::Parallel) {
+		*position += 1;
+		parts.push(parse_term(tokens, position)?);
+	}
+
+	Ok(group(parts, Network::Parallel))
+}
+
+//INFO: term := '(' series ')' | element
+fn parse_term(tokens: &[Token], position: &mut usize) -> Result<Network, CalcError> {
+	let token = tokens.get(*position).ok_or(CalcError::InvalidNetwork("missing part at the end".to_string()))?;
+	*position += 1;
+
+	match token {
+		Token::Open => {
+			let network = parse_series(tokens, position)?;
+
+			match tokens.get(*position) {
+				Some(Token::Close) => {
+					*position += 1;
+					Ok(network)
+				}
+				_ => Err(CalcError::InvalidNetwork("missing ')'".to_string())),
+			}
+		}
+		Token::Element(text) => parse_element(text),
+		Token::Series | Token::Parallel => Err(CalcError::InvalidNetwork("two joins in a row".to_string())),
+		Token::Close => Err(CalcError::InvalidNetwork("empty group '()'".to_string())),
+	}
+}
+
+fn parse_element(text: &str) -> Result<Network, CalcError> {
+	let value: EngineeringValue = text.parse()?;
+
+	match value.unit {
+		Some(OHM_BASE_TYPE) => Ok(Network::Resistor(value.scaled())),
+		Some(HENRY_BASE_TYPE) => Ok(Network::Inductor(value.scaled())),
+		Some(FARAD_BASE_TYPE) => Ok(Network::Capacitor(value.scaled())),
+		_ => Err(CalcError::InvalidNetwork(format!("'{text}' needs a unit, Ω, H or F"))),
+	}
+}
+
+//INFO: a group of one is just that part
+fn group(parts: Vec<Network>, kind: fn(Vec<Network>) -> Network) -> Network {
+	if parts.len() == 1 {
+		return parts.into_iter().next().unwrap();
+	}
+
+	kind(parts)
+}
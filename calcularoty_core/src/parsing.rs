@@ -0,0 +1,178 @@
+//! Parsing of values written the way they are printed on parts and schematics:
+//! `4.7u`, `4u7`, `10 nF`, `2.2kΩ`, `1e-6`, `100p`, and sizes such as `10mm`, `2 m` or `4cm²`.
+
+use std::{str::FromStr, sync::LazyLock};
+
+use astro_float::{BigFloat, Radix};
+use regex::Regex;
+
+use crate::consts::{METER_BASE_TYPE, SQUARE_METER_BASE_TYPE};
+use crate::conversions::{convert_to_base, pow10};
+use crate::error::CalcError;
+use crate::precision::{precision, rounding_mode, with_consts};
+use crate::types::{BFloat, UnitType};
+use crate::utils::get_unit_group;
+
+//INFO: longest symbols first, so "Hz" is not read as "H"
+const UNIT_SYMBOLS: [(&str, &str); 10] = [
+	("ohms", "Ω"),
+	("ohm", "Ω"),
+	("Hz", "Hz"),
+	("m²", "m²"),
+	("Ω", "Ω"),
+	("\u{2126}", "Ω"),
+	("F", "F"),
+	("H", "H"),
+	("S", "S"),
+	("m", "m"),
+];
+
+//INFO: only read as a unit in a size field, anywhere else "10m" is 10 milli
+const SIZE_SYMBOLS: [&str; 2] = [METER_BASE_TYPE, SQUARE_METER_BASE_TYPE];
+
+//INFO: "4.7u", "1e-6", "2.2 k", centi is only taken in size fields
+static PLAIN_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"^([-+]?(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?)\s*([qyzafpnuμµmckKMGTPEZYQ]?)$").unwrap()
+});
+
+//INFO: "4u7", "2k2", "4R7", the prefix stands in for the decimal point
+static INFIX_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"^([-+]?\d+)([qyzafpnuμµmkKMGTPEZYQRr])(\d+)$").unwrap()
+});
+
+/// A number with an optional SI prefix and unit symbol, as typed into an input field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EngineeringValue {
+	/// The number without its prefix, exponent notation already applied.
+	pub number: BFloat,
+	/// Power of ten of the SI prefix, if one was written.
+	pub prefix: Option<i32>,
+	/// Base unit symbol, if one was written.
+	pub unit: Option<&'static str>,
+}
+
+impl EngineeringValue {
+	/// Parses the text of a field of `unit_type`. In a size or area field a trailing `m` or `m²`
+	/// is the unit, so `2m` is 2 m, `10mm` is 10 mm and `4cm²` is 4 cm². Everywhere else, and in
+	/// [`EngineeringValue::from_str`], a trailing `m` stays the milli prefix, `10m` is 10 mH in
+	/// an inductance field.
+	pub fn parse_for(s: &str, unit_type: &UnitType) -> Result<Self, CalcError> {
+		parse(s, matches!(unit_type, UnitType::Meter | UnitType::SquareMeter))
+	}
+
+	/// The value with its prefix applied, in the base unit when a prefix or unit was written. The
+	/// prefix of an area belongs to the length, `1mm²` is 10⁻⁶ m².
+	pub fn scaled(&self) -> BFloat {
+		match (self.prefix, self.unit) {
+			(Some(exponent), Some(SQUARE_METER_BASE_TYPE)) => self.number.clone() * pow10(2 * exponent),
+			(Some(exponent), _) => self.number.clone() * pow10(exponent),
+			(None, _) => self.number.clone(),
+		}
+	}
+
+	/// Converts to the base unit of `unit_type`. A written prefix or unit takes precedence over
+	/// `unit_label`, the unit picked next to the field, which only applies to bare numbers.
+	pub fn to_base(&self, unit_type: &UnitType, unit_label: &str) -> Result<BFloat, CalcError> {
+		if let Some(unit) = self.unit
+			&& get_unit_group(unit) != *unit_type
+		{
+			return Err(CalcError::UnknownUnit(unit.to_string()));
+		}
+
+		//INFO: "10m" on an area could be 10 milli m² or 10 mm², it has to say which
+		if *unit_type == UnitType::SquareMeter && self.prefix.is_some() && self.unit.is_none() {
+			return Err(CalcError::AreaPrefix);
+		}
+
+		if self.prefix.is_some() || self.unit.is_some() {
+			return Ok(self.scaled());
+		}
+
+		convert_to_base(self.number.clone(), unit_type, unit_label)
+	}
+}
+
+impl FromStr for EngineeringValue {
+	type Err = CalcError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		parse(s, false)
+	}
+}
+
+fn parse(s: &str, sizes: bool) -> Result<EngineeringValue, CalcError> {
+	let trimmed = s.trim();
+
+	if trimmed.is_empty() {
+		return Err(CalcError::EmptyInput);
+	}
+
+	let symbol = UNIT_SYMBOLS
+		.iter()
+		.filter(|(symbol, _)| sizes || !SIZE_SYMBOLS.contains(symbol))
+		.find(|(symbol, _)| trimmed.ends_with(symbol));
+
+	let (rest, unit) = match symbol {
+		Some((symbol, base)) => (trimmed[..trimmed.len() - symbol.len()].trim_end(), Some(*base)),
+		None => (trimmed, None),
+	};
+
+	let invalid = || CalcError::InvalidNumber(s.to_string());
+
+	let (number, prefix) = if let Some(captures) = PLAIN_REGEX.captures(rest) {
+		let prefix = match captures.get(2).unwrap().as_str() {
+			"" => None,
+			prefix => Some(prefix_exponent(prefix, sizes).ok_or_else(invalid)?),
+		};
+
+		(parse_plain(captures.get(1).unwrap().as_str()).ok_or_else(invalid)?, prefix)
+	} else if let Some(captures) = INFIX_REGEX.captures(rest) {
+		let number = format!("{}.{}", captures.get(1).unwrap().as_str(), captures.get(3).unwrap().as_str());
+		let prefix = match captures.get(2).unwrap().as_str() {
+			"R" | "r" => None,
+			prefix => prefix_exponent(prefix, sizes),
+		};
+
+		(parse_plain(&number).ok_or_else(invalid)?, prefix)
+	} else {
+		return Err(invalid());
+	};
+
+	Ok(EngineeringValue { number, prefix, unit })
+}
+
+//INFO: ronto and ronna are left out, "r" and "R" mark the decimal point in "4R7"
+fn prefix_exponent(prefix: &str, sizes: bool) -> Option<i32> {
+	match prefix {
+		"c" if sizes => Some(-2),
+		"q" => Some(-30),
+		"y" => Some(-24),
+		"z" => Some(-21),
+		"a" => Some(-18),
+		"f" => Some(-15),
+		"p" => Some(-12),
+		"n" => Some(-9),
+		"u" | "μ" | "µ" => Some(-6),
+		"m" => Some(-3),
+		"k" | "K" => Some(3),
+		"M" => Some(6),
+		"G" => Some(9),
+		"T" => Some(12),
+		"P" => Some(15),
+		"E" => Some(18),
+		"Z" => Some(21),
+		"Y" => Some(24),
+		"Q" => Some(30),
+		_ => None,
+	}
+}
+
+//INFO: astro-float parses garbage into NaN instead of failing; from_str would read at 64 bits whatever the
+//INFO: working precision, so the digits are read at precision() like every other operation
+fn parse_plain(s: &str) -> Option<BFloat> {
+	let value = with_consts(|consts| BigFloat::parse(s, Radix::Dec, precision(), rounding_mode(), consts));
+
+	match value.is_nan() {
+		true => None,
+		false => Some(value.into()),
+	}
+}
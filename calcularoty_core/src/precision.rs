@@ -0,0 +1,75 @@
+//! Working precision and rounding mode of every [`BFloat`](crate::BFloat) operation.
+//!
+//! Both are process-wide runtime settings: a low precision keeps quick checks fast, a high one
+//! is there for verifying results. Changing them only affects operations done afterwards.
+
+use std::{cell::RefCell, sync::atomic::{AtomicU8, AtomicUsize, Ordering}};
+
+use astro_float::{Consts, RoundingMode};
+
+/// Mantissa bits used until [`set_precision`] is called.
+pub const DEFAULT_PRECISION: usize = 1024;
+
+/// Precisions offered in the settings screen, from quick checks to verification work.
+pub const PRECISION_PRESETS: [usize; 7] = [128, 256, 512, 1024, 2048, 4096, 8192];
+
+/// Rounding modes offered in the settings screen, the first one is the default.
+pub const ROUNDING_MODES: [(&str, RoundingMode); 6] = [
+	("ToEven", RoundingMode::ToEven),
+	("ToOdd", RoundingMode::ToOdd),
+	("Up", RoundingMode::Up),
+	("Down", RoundingMode::Down),
+	("ToZero", RoundingMode::ToZero),
+	("FromZero", RoundingMode::FromZero),
+];
+
+//INFO: below this, 10^30 of the unit tables stops being exact
+const MIN_PRECISION: usize = 128;
+
+static PRECISION: AtomicUsize = AtomicUsize::new(DEFAULT_PRECISION);
+static ROUNDING_MODE: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+	static CONSTS: RefCell<Consts> = RefCell::new(Consts::new().expect("consts cache could not init"));
+}
+
+/// Current mantissa precision, in bits.
+pub fn precision() -> usize {
+	PRECISION.load(Ordering::Relaxed)
+}
+
+/// Sets the mantissa precision, in bits, clamped to at least 128.
+pub fn set_precision(bits: usize) {
+	PRECISION.store(bits.max(MIN_PRECISION), Ordering::Relaxed);
+}
+
+/// Current rounding mode.
+pub fn rounding_mode() -> RoundingMode {
+	ROUNDING_MODES[ROUNDING_MODE.load(Ordering::Relaxed) as usize].1
+}
+
+/// Name of the current rounding mode, as listed in [`ROUNDING_MODES`].
+pub fn rounding_mode_name() -> &'static str {
+	ROUNDING_MODES[ROUNDING_MODE.load(Ordering::Relaxed) as usize].0
+}
+
+/// Sets the rounding mode by its name in [`ROUNDING_MODES`], returns false for an unknown name.
+pub fn set_rounding_mode(name: &str) -> bool {
+	match ROUNDING_MODES.iter().position(|(mode_name, _)| *mode_name == name) {
+		Some(index) => {
+			ROUNDING_MODE.store(index as u8, Ordering::Relaxed);
+			true
+		}
+		None => false,
+	}
+}
+
+/// Decimal digits a precision of `bits` can hold, floor(bits · log10 2).
+pub fn significant_digits(bits: usize) -> usize {
+	(bits as f64 * std::f64::consts::LOG10_2).floor() as usize
+}
+
+//INFO: pi and friends are cached per thread, computing them again at every call is slow
+pub(crate) fn with_consts<R>(f: impl FnOnce(&mut Consts) -> R) -> R {
+	CONSTS.with(|consts| f(&mut consts.borrow_mut()))
+}
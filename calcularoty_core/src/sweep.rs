@@ -0,0 +1,112 @@
+//! Frequency sweeps of a series or parallel RLC circuit.
+//!
+//! [`sweep_frequencies`] spreads the points between the bounds, evenly or evenly per decade,
+//! and [`sweep_rlc`] evaluates the circuit at each of them.
+
+use std::{fmt::Display, str::FromStr};
+
+use num_traits::Zero;
+
+use crate::calculations::{calculate_impedance_parallel, calculate_impedance_series};
+use crate::conversions::get_omega;
+use crate::error::CalcError;
+use crate::types::BFloat;
+
+/// Most points a sweep may have, each one is a full precision evaluation.
+pub const MAX_SWEEP_POINTS: usize = 1000;
+
+/// Names of the scales, in the order [`SweepScale::from_str`] knows them.
+pub const SWEEP_SCALES: [&str; 2] = ["Linear", "Log"];
+
+/// How the points of a sweep are spread between its bounds.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SweepScale {
+	/// Same step in Hz between neighbours.
+	Linear,
+	/// Same ratio between neighbours, so the same number of points per decade.
+	#[default]
+	Logarithmic,
+}
+
+impl FromStr for SweepScale {
+	type Err = CalcError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim().to_lowercase().as_str() {
+			"linear" | "lin" => Ok(SweepScale::Linear),
+			"log" | "logarithmic" => Ok(SweepScale::Logarithmic),
+			_ => Err(CalcError::InvalidSweep(format!("'{s}' is not a scale, Linear or Log"))),
+		}
+	}
+}
+
+impl Display for SweepScale {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			SweepScale::Linear => write!(f, "{}", SWEEP_SCALES[0]),
+			SweepScale::Logarithmic => write!(f, "{}", SWEEP_SCALES[1]),
+		}
+	}
+}
+
+/// One row of a sweep, frequency in Hz, impedances in Ω and phase in radians.
+#[derive(Clone, Debug)]
+pub struct SweepPoint {
+	pub frequency: BFloat,
+	pub impedance: BFloat,
+	pub phase: BFloat,
+	pub inductive_reactance: BFloat,
+	pub capacitive_reactance: BFloat,
+}
+
+/// `points` frequencies from `start` to `stop`, both included, in base units.
+pub fn sweep_frequencies(start: BFloat, stop: BFloat, points: usize, scale: SweepScale) -> Result<Vec<BFloat>, CalcError> {
+	if !(2..=MAX_SWEEP_POINTS).contains(&points) {
+		return Err(CalcError::InvalidSweep(format!("between 2 and {MAX_SWEEP_POINTS} points are needed")));
+	}
+	if start <= BFloat::zero() || stop <= BFloat::zero() {
+		return Err(CalcError::InvalidSweep("the bounds must be above 0 Hz".to_string()));
+	}
+	if start == stop {
+		return Err(CalcError::InvalidSweep("start and stop are the same".to_string()));
+	}
+
+	let steps = BFloat::from((points - 1) as u64);
+
+	//INFO: each point is worked out from the start, so rounding does not pile up along the sweep
+	let frequencies = match scale {
+		SweepScale::Linear => {
+			let step = (stop - start.clone()) / steps;
+			(0..points).map(|i| start.clone() + step.clone() * BFloat::from(i as u64)).collect()
+		}
+		SweepScale::Logarithmic => {
+			let step = (stop / start.clone()).ln() / steps;
+			(0..points).map(|i| start.clone() * (step.clone() * BFloat::from(i as u64)).exp()).collect()
+		}
+	};
+
+	Ok(frequencies)
+}
+
+/// Evaluates the RLC circuit at every frequency, a missing resistor as in
+/// [`calculate_impedance_series`] and [`calculate_impedance_parallel`].
+pub fn sweep_rlc(r: Option<BFloat>, l: BFloat, c: BFloat, parallel: bool, frequencies: &[BFloat]) -> Vec<SweepPoint> {
+	frequencies
+		.iter()
+		.map(|frequency| {
+			let omega = get_omega(frequency.clone());
+
+			let result = match parallel {
+				false => calculate_impedance_series(r.clone(), l.clone(), c.clone(), omega),
+				true => calculate_impedance_parallel(r.clone(), l.clone(), c.clone(), omega),
+			};
+
+			SweepPoint {
+				frequency: frequency.clone(),
+				impedance: result.magnitude(),
+				phase: result.phase(),
+				inductive_reactance: result.inductive_reactance,
+				capacitive_reactance: result.capacitive_reactance,
+			}
+		})
+		.collect()
+}
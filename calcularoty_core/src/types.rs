@@ -0,0 +1,324 @@
+use std::{fmt::Display, ops::{Add, Div, Mul, Neg, Rem, Sub}, str::FromStr};
+use astro_float::{BigFloat, Radix, Sign, Word};
+use num_complex::Complex;
+use num_traits::{Num, One, Pow, Zero};
+
+use crate::{complex::ComplexExt, error::CalcError, parsing::EngineeringValue, consts::{pi, FARAD_BASE_TYPE, HENRY_BASE_TYPE, HERTZ_BASE_TYPE, OHM_BASE_TYPE, SIEMENS_BASE_TYPE, METER_BASE_TYPE, SQUARE_METER_BASE_TYPE}, precision::{precision, rounding_mode, with_consts}, formatting::{self, NumberStyle}, utils};
+
+/// Physical quantity a value or unit label belongs to.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum UnitType {
+	Hertz,
+	Farad,
+	Henry,
+	Ohm,
+	Siemens,
+	Meter,
+	SquareMeter,
+	NotSelected,
+}
+
+impl UnitType {
+	/// Label of the unprefixed unit, e.g. `"Hz"` for [`UnitType::Hertz`].
+	pub fn base_unit(&self) -> &'static str {
+		match self {
+			UnitType::Hertz => HERTZ_BASE_TYPE,
+			UnitType::Farad => FARAD_BASE_TYPE,
+			UnitType::Henry => HENRY_BASE_TYPE,
+			UnitType::Ohm => OHM_BASE_TYPE,
+			UnitType::Siemens => SIEMENS_BASE_TYPE,
+			UnitType::Meter => METER_BASE_TYPE,
+			UnitType::SquareMeter => SQUARE_METER_BASE_TYPE,
+			UnitType::NotSelected => "",
+		}
+	}
+}
+
+/// Arbitrary precision float used by every calculation.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct BFloat(pub BigFloat);
+
+macro_rules! impl_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BFloat {
+                fn from(x: $t) -> Self {
+                    BigFloat::from(x).into()
+                }
+            }
+        )*
+    }
+}
+
+//INFO: accepts engineering notation, "4u7" parses to 4.7e-6, see parsing::EngineeringValue
+impl FromStr for BFloat {
+	type Err = CalcError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let value: EngineeringValue = s.parse()?;
+
+		Ok(value.scaled())
+	}
+}
+
+impl Add for BFloat {
+	type Output = Self;
+	fn add(self, rhs: Self) -> Self::Output {
+		BigFloat::add(&self.0, &rhs.0, precision(), rounding_mode()).into()
+	}
+}
+
+impl Sub for BFloat {
+	type Output = Self;
+	fn sub(self, rhs: Self) -> Self::Output {
+	    BigFloat::sub(&self.0, &rhs.0, precision(), rounding_mode()).into()
+	}
+}
+
+impl Mul for BFloat {
+	type Output = Self;
+	fn mul(self, rhs: Self) -> Self::Output {
+	    BigFloat::mul(&self.0, &rhs.0, precision(), rounding_mode()).into()
+	}
+}
+
+impl Div for BFloat {
+	type Output = Self;
+	fn div(self, rhs: Self) -> Self::Output {
+	    BigFloat::div(&self.0, &rhs.0, precision(), rounding_mode()).into()
+	}
+}
+
+//INFO: truncated remainder, a - b*trunc(a/b), same sign as the dividend like f64's %
+impl Rem for BFloat {
+	type Output = Self;
+	fn rem(self, rhs: Self) -> Self::Output {
+		let quotient = BFloat((self.clone() / rhs.clone()).0.int());
+		self - rhs * quotient
+	}
+}
+
+impl<T> Pow<T> for BFloat
+	where T: Into<usize>,
+{
+	type Output = Self;
+	fn pow(self, rhs: T) -> Self::Output {
+		self.0.powi(rhs.into(), precision(), rounding_mode()).into()
+	}
+}
+
+impl BFloat {
+	/// Square root.
+	pub fn sqrt(&self) -> Self {
+		self.0.sqrt(precision(), rounding_mode()).into()
+	}
+
+	/// Absolute value.
+	pub fn abs(&self) -> Self {
+		BFloat(self.0.abs())
+	}
+
+	/// Not-a-number, used as the "empty field" marker.
+	#[inline]
+	pub fn nan() -> Self {
+		BFloat(BigFloat::nan(None))
+	}
+
+	/// Arctangent, in radians.
+	pub fn atan(&self) -> Self {
+		with_consts(|consts| self.0.atan(precision(), rounding_mode(), consts)).into()
+	}
+
+	/// Angle of the point (x, y), in the range (-pi, pi].
+	pub fn atan2(y: &BFloat, x: &BFloat) -> Self {
+		if x.0.is_zero() {
+			if y.0.is_zero() {
+				return BFloat::zero();
+			}
+			if y.0.is_negative() {
+				return -pi() / 2.into();
+			}
+			return pi() / 2.into();
+		}
+
+		let angle = (y.clone() / x.clone()).atan();
+
+		if x.0.is_positive() {
+			return angle;
+		}
+		if y.0.is_negative() {
+			return angle - pi();
+		}
+		angle + pi()
+	}
+
+	/// Sine of an angle in radians.
+	pub fn sin(&self) -> Self {
+		with_consts(|consts| self.0.sin(precision(), rounding_mode(), consts)).into()
+	}
+
+	/// Cosine of an angle in radians.
+	pub fn cos(&self) -> Self {
+		with_consts(|consts| self.0.cos(precision(), rounding_mode(), consts)).into()
+	}
+
+	/// Natural logarithm.
+	pub fn ln(&self) -> Self {
+		with_consts(|consts| self.0.ln(precision(), rounding_mode(), consts)).into()
+	}
+
+	/// e raised to this value.
+	pub fn exp(&self) -> Self {
+		with_consts(|consts| self.0.exp(precision(), rounding_mode(), consts)).into()
+	}
+
+	/// Nearest `f64`, lossy, for placing things on screen and never for results.
+	pub fn to_f64(&self) -> f64 {
+		if self.0.is_nan() {
+			return f64::NAN;
+		}
+		if self.0.is_inf() {
+			return if self.0.is_negative() { f64::NEG_INFINITY } else { f64::INFINITY };
+		}
+
+		//INFO: the value is 0.m * 2^e, the top word of the mantissa holds more bits than f64 keeps
+		let Some((words, _, sign, exponent, _)) = self.0.as_raw_parts() else {
+			return f64::NAN;
+		};
+		let Some(top) = words.last() else {
+			return 0.0;
+		};
+
+		let value = *top as f64 / 2f64.powi(Word::BITS as i32) * 2f64.powi(exponent);
+
+		match sign {
+			Sign::Neg => -value,
+			Sign::Pos => value,
+		}
+	}
+
+	/// Converts an angle in radians to degrees.
+	#[inline]
+	pub fn to_degrees(&self) -> Self {
+		self.clone() * 180.into() / pi()
+	}
+}
+
+/// Complex impedance of a circuit at one frequency, with its admittance and branch reactances.
+pub struct ImpedanceResult {
+	pub impedance: Complex<BFloat>,
+	pub admittance: Complex<BFloat>,
+	pub inductive_reactance: BFloat,
+	pub capacitive_reactance: BFloat,
+}
+
+impl ImpedanceResult {
+	/// Magnitude |Z|, in Ω.
+	pub fn magnitude(&self) -> BFloat {
+		self.impedance.magnitude()
+	}
+
+	/// Phase arg(Z), in radians.
+	pub fn phase(&self) -> BFloat {
+		self.impedance.argument()
+	}
+
+	/// Magnitude |Y|, in S.
+	pub fn admittance_magnitude(&self) -> BFloat {
+		self.admittance.magnitude()
+	}
+}
+
+impl Display for BFloat {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.to_styled_string(NumberStyle::default()))
+	}
+}
+
+impl Neg for BFloat {
+	type Output = Self;
+	fn neg(self) -> Self::Output {
+	    self.0.neg().into()
+	}
+}
+
+impl Zero for BFloat {
+	fn is_zero(&self) -> bool {
+	    self.0.is_zero()
+	}
+	fn zero() -> Self {
+	    0.into()
+	}
+	fn set_zero(&mut self) {
+	    *self = Self::zero()
+	}
+}
+
+impl One for BFloat {
+	fn is_one(&self) -> bool
+	    where
+	        Self: PartialEq, {
+	    self.0 == Self::from(1).0
+	}
+	fn one() -> Self {
+	    1.into()
+	}
+	fn set_one(&mut self) {
+	    *self = Self::one()
+	}
+}
+
+//INFO: makes Complex<BFloat> divisible, num_complex asks for Num on its division and inverse
+impl Num for BFloat {
+	type FromStrRadixErr = CalcError;
+	fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		let radix = match radix {
+			2 => Radix::Bin,
+			8 => Radix::Oct,
+			10 => return str.parse(),
+			16 => Radix::Hex,
+			_ => return Err(CalcError::InvalidNumber(str.to_string())),
+		};
+
+		let value = with_consts(|consts| BigFloat::parse(str, radix, precision(), rounding_mode(), consts));
+
+		match value.is_nan() {
+			true => Err(CalcError::InvalidNumber(str.to_string())),
+			false => Ok(value.into()),
+		}
+	}
+}
+
+/// Resonance figures of a series or parallel RLC circuit, frequencies in Hz and impedance in Ω.
+pub struct ResonanceResult {
+	pub resonant_frequency: BFloat,
+	pub characteristic_impedance: BFloat,
+	pub damping_ratio: BFloat,
+	pub quality_factor: BFloat,
+	pub bandwidth: BFloat,
+	pub lower_frequency: BFloat,
+	pub upper_frequency: BFloat,
+}
+
+impl From<BigFloat> for BFloat {
+	fn from(value: BigFloat) -> Self {
+	    Self(value)
+	}
+}
+
+impl_from!(
+	f32, f64,
+	u8, u16, u32, u64, u128,
+	i8, i16, i32, i64, i128
+);
+
+impl BFloat {
+	/// Every significant digit as a plain decimal string, see [`utils::bigfloat_to_plain_decimal`].
+	pub fn as_decimal_string(&self) -> String {
+		utils::bigfloat_to_plain_decimal(self).unwrap_or("0".to_string())
+	}
+
+	/// The value written in `style`, see [`formatting::format_number`].
+	pub fn to_styled_string(&self, style: NumberStyle) -> String {
+		formatting::format_number(self, style).unwrap_or("0".to_string())
+	}
+}
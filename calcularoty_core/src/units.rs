@@ -0,0 +1,161 @@
+//! Unit tables: every label of a family mapped to its power-of-ten exponent relative to the base unit.
+//! Each family covers the engineering SI prefixes from quecto (10⁻³⁰) to quetta (10³⁰), smallest first.
+
+use phf::{phf_ordered_map, OrderedMap};
+
+/// Frequency prefixes, base `Hz`.
+pub static HERTZ_UNITS: OrderedMap<&str, i32> = phf_ordered_map! {
+	"qHz" => -30,
+	"rHz" => -27,
+	"yHz" => -24,
+	"zHz" => -21,
+	"aHz" => -18,
+	"fHz" => -15,
+	"pHz" => -12,
+	"nHz" => -9,
+	"μHz" => -6,
+	"mHz" => -3,
+	"Hz" => 0,
+	"kHz" => 3,
+	"MHz" => 6,
+	"GHz" => 9,
+	"THz" => 12,
+	"PHz" => 15,
+	"EHz" => 18,
+	"ZHz" => 21,
+	"YHz" => 24,
+	"RHz" => 27,
+	"QHz" => 30,
+};
+/// Capacitance prefixes, base `F`.
+pub static FARAD_UNITS: OrderedMap<&str, i32> = phf_ordered_map! {
+	"qF" => -30,
+	"rF" => -27,
+	"yF" => -24,
+	"zF" => -21,
+	"aF" => -18,
+	"fF" => -15,
+	"pF" => -12,
+	"nF" => -9,
+	"μF" => -6,
+	"mF" => -3,
+	"F" => 0,
+	"kF" => 3,
+	"MF" => 6,
+	"GF" => 9,
+	"TF" => 12,
+	"PF" => 15,
+	"EF" => 18,
+	"ZF" => 21,
+	"YF" => 24,
+	"RF" => 27,
+	"QF" => 30,
+};
+/// Inductance prefixes, base `H`.
+pub static HENRY_UNITS: OrderedMap<&str, i32> = phf_ordered_map! {
+	"qH" => -30,
+	"rH" => -27,
+	"yH" => -24,
+	"zH" => -21,
+	"aH" => -18,
+	"fH" => -15,
+	"pH" => -12,
+	"nH" => -9,
+	"μH" => -6,
+	"mH" => -3,
+	"H" => 0,
+	"kH" => 3,
+	"MH" => 6,
+	"GH" => 9,
+	"TH" => 12,
+	"PH" => 15,
+	"EH" => 18,
+	"ZH" => 21,
+	"YH" => 24,
+	"RH" => 27,
+	"QH" => 30,
+};
+/// Resistance, reactance and impedance prefixes, base `Ω`.
+pub static OHM_UNITS: OrderedMap<&str, i32> = phf_ordered_map! {
+	"qΩ" => -30,
+	"rΩ" => -27,
+	"yΩ" => -24,
+	"zΩ" => -21,
+	"aΩ" => -18,
+	"fΩ" => -15,
+	"pΩ" => -12,
+	"nΩ" => -9,
+	"μΩ" => -6,
+	"mΩ" => -3,
+	"Ω" => 0,
+	"kΩ" => 3,
+	"MΩ" => 6,
+	"GΩ" => 9,
+	"TΩ" => 12,
+	"PΩ" => 15,
+	"EΩ" => 18,
+	"ZΩ" => 21,
+	"YΩ" => 24,
+	"RΩ" => 27,
+	"QΩ" => 30,
+};
+/// Length prefixes, base `m`, with `cm` for coil and plate sizes. A trailing `m` typed into a size
+/// field is the meter, `2m` is 2 m and `10mm` is 10 mm.
+pub static METER_UNITS: OrderedMap<&str, i32> = phf_ordered_map! {
+	"qm" => -30,
+	"rm" => -27,
+	"ym" => -24,
+	"zm" => -21,
+	"am" => -18,
+	"fm" => -15,
+	"pm" => -12,
+	"nm" => -9,
+	"μm" => -6,
+	"mm" => -3,
+	"cm" => -2,
+	"m" => 0,
+	"km" => 3,
+	"Mm" => 6,
+	"Gm" => 9,
+	"Tm" => 12,
+	"Pm" => 15,
+	"Em" => 18,
+	"Zm" => 21,
+	"Ym" => 24,
+	"Rm" => 27,
+	"Qm" => 30,
+};
+/// Area units, base `m²`. The exponents are those of the squared length, `mm²` is 10⁻⁶ m². A
+/// prefix typed into a field needs the unit after it, `10mm²`, a bare `10m` is refused.
+pub static SQUARE_METER_UNITS: OrderedMap<&str, i32> = phf_ordered_map! {
+	"nm²" => -18,
+	"μm²" => -12,
+	"mm²" => -6,
+	"cm²" => -4,
+	"m²" => 0,
+	"km²" => 6,
+};
+/// Conductance and admittance prefixes, base `S`.
+pub static SIEMENS_UNITS: OrderedMap<&str, i32> = phf_ordered_map! {
+	"qS" => -30,
+	"rS" => -27,
+	"yS" => -24,
+	"zS" => -21,
+	"aS" => -18,
+	"fS" => -15,
+	"pS" => -12,
+	"nS" => -9,
+	"μS" => -6,
+	"mS" => -3,
+	"S" => 0,
+	"kS" => 3,
+	"MS" => 6,
+	"GS" => 9,
+	"TS" => 12,
+	"PS" => 15,
+	"ES" => 18,
+	"ZS" => 21,
+	"YS" => 24,
+	"RS" => 27,
+	"QS" => 30,
+};
@@ -0,0 +1,34 @@
+use crate::units::{HERTZ_UNITS, FARAD_UNITS, HENRY_UNITS, OHM_UNITS, SIEMENS_UNITS, METER_UNITS, SQUARE_METER_UNITS};
+use crate::types::{BFloat, UnitType};
+use crate::consts::AUTO_UNIT;
+use crate::formatting::{format_number, NumberStyle};
+
+/// Returns the family a unit label such as `"kHz"`, `"μF"` or `"Auto H"` belongs to,
+/// or [`UnitType::NotSelected`] if no unit table knows the label.
+pub fn get_unit_group(value: &str) -> UnitType {
+	if let Some(base) = value.strip_prefix(AUTO_UNIT) {
+		return get_unit_group(base.trim());
+	}
+
+	if HERTZ_UNITS.contains_key(value) {
+		return UnitType::Hertz;
+	} else if FARAD_UNITS.contains_key(value) {
+		return UnitType::Farad;
+	} else if HENRY_UNITS.contains_key(value) {
+	   return UnitType::Henry; 
+	} else if OHM_UNITS.contains_key(value) {
+		return UnitType::Ohm;
+	} else if SIEMENS_UNITS.contains_key(value) {
+		return UnitType::Siemens;
+	} else if METER_UNITS.contains_key(value) {
+		return UnitType::Meter;
+	} else if SQUARE_METER_UNITS.contains_key(value) {
+		return UnitType::SquareMeter;
+	}
+	UnitType::NotSelected
+}
+
+/// Formats `n` as a plain decimal string with every significant digit and no exponent.
+pub fn bigfloat_to_plain_decimal(n: &BFloat) -> Result<String, astro_float::Error> {
+	format_number(n, NumberStyle::Plain)
+}
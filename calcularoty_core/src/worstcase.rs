@@ -0,0 +1,110 @@
+//! Worst-case analysis: how much each part moves a result, and the furthest the result gets when
+//! every part sits at an edge of its tolerance band.
+//!
+//! Sensitivities are normalized, S = (∂y/y) / (∂x/x), so -0.5 means a part 1 % high takes the
+//! result 0.5 % low. They come from a central difference at full [`BFloat`] precision.
+
+use num_traits::One;
+
+use crate::conversions::pow10;
+use crate::error::CalcError;
+use crate::montecarlo::Toleranced;
+use crate::precision::{precision, significant_digits};
+use crate::types::BFloat;
+
+/// Most parts a corner run takes, every extra part doubles the evaluations.
+pub const MAX_CORNER_PARTS: usize = 12;
+
+/// The range of one result over the corners.
+#[derive(Clone, Debug)]
+pub struct Extremes {
+	pub nominal: BFloat,
+	pub min: BFloat,
+	pub max: BFloat,
+	/// Where each part sits at the minimum, -1 at the low edge, 1 at the high one, 0 at nominal.
+	pub min_corner: Vec<i8>,
+	/// The same at the maximum.
+	pub max_corner: Vec<i8>,
+}
+
+/// Sensitivity of every result `evaluate` returns to every part, indexed `[result][part]`.
+pub fn sensitivities(
+	parts: &[BFloat],
+	evaluate: impl Fn(&[BFloat]) -> Result<Vec<BFloat>, CalcError>,
+) -> Result<Vec<Vec<BFloat>>, CalcError> {
+	//INFO: a step of a third of the digits balances the truncation error against the rounding one
+	let step = pow10(-((significant_digits(precision()) / 3).max(1) as i32));
+	let two = BFloat::from(2);
+
+	let nominal = evaluate(parts)?;
+	let mut results: Vec<Vec<BFloat>> = vec![Vec::new(); nominal.len()];
+
+	for index in 0..parts.len() {
+		let moved = |factor: BFloat| -> Result<Vec<BFloat>, CalcError> {
+			let mut values = parts.to_vec();
+			values[index] = values[index].clone() * factor;
+			evaluate(&values)
+		};
+
+		let above = moved(BFloat::one() + step.clone())?;
+		let below = moved(BFloat::one() - step.clone())?;
+
+		for (result, column) in results.iter_mut().enumerate() {
+			let change = (above[result].clone() - below[result].clone()) / nominal[result].clone();
+			column.push(change / (two.clone() * step.clone()));
+		}
+	}
+
+	Ok(results)
+}
+
+/// Evaluates every corner of the tolerance bands, plus the nominal point, and keeps the extremes
+/// of each result. A result that peaks inside the bands, like |Z| near resonance, can go further
+/// between the corners than on them.
+pub fn worst_case(
+	parts: &[Toleranced],
+	evaluate: impl Fn(&[BFloat]) -> Result<Vec<BFloat>, CalcError>,
+) -> Result<Vec<Extremes>, CalcError> {
+	if parts.len() > MAX_CORNER_PARTS {
+		return Err(CalcError::InvalidAnalysis(format!("at most {MAX_CORNER_PARTS} parts have corners worked out")));
+	}
+
+	let nominal = evaluate(&parts.iter().map(|part| part.nominal.clone()).collect::<Vec<BFloat>>())?;
+
+	let mut extremes = nominal
+		.iter()
+		.map(|value| Extremes {
+			nominal: value.clone(),
+			min: value.clone(),
+			max: value.clone(),
+			min_corner: vec![0; parts.len()],
+			max_corner: vec![0; parts.len()],
+		})
+		.collect::<Vec<Extremes>>();
+
+	//INFO: bit i of the corner puts part i at its high edge
+	for corner in 0..(1usize << parts.len()) {
+		let edges = (0..parts.len())
+			.map(|index| if corner >> index & 1 == 1 { 1 } else { -1 })
+			.collect::<Vec<i8>>();
+
+		let values = parts
+			.iter()
+			.zip(&edges)
+			.map(|(part, edge)| part.at(&BFloat::from(*edge)))
+			.collect::<Vec<BFloat>>();
+
+		for (extremes, value) in extremes.iter_mut().zip(evaluate(&values)?) {
+			if value < extremes.min {
+				extremes.min = value.clone();
+				extremes.min_corner = edges.clone();
+			}
+			if value > extremes.max {
+				extremes.max = value;
+				extremes.max_corner = edges.clone();
+			}
+		}
+	}
+
+	Ok(extremes)
+}
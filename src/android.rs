@@ -1,40 +1,83 @@
 use std::{
     ffi::CString,
     io::{Cursor, Read},
+    sync::OnceLock,
 };
-use rodio::{Decoder, OutputStreamBuilder, Sink};
+use jni::{objects::JObject, JavaVM};
+use rodio::{decoder::LoopedDecoder, Decoder};
 use ndk::asset::AssetManager;
+use slint::android::AndroidApp;
 use std::error::Error;
 
+use crate::music::Music;
+use crate::settings::Settings;
+
 #[path = "logic.rs"]
 mod logic;
 
-pub fn run_app(asset_manager: AssetManager) -> Result<(), Box<dyn Error>> {
-    std::thread::spawn(||{
-        let _ = play_background_music_android(asset_manager);
-    });
+//INFO: kept for the calls into Java, the activity starts the share sheet
+static APP: OnceLock<AndroidApp> = OnceLock::new();
 
-    logic::start_ui()
-}
+pub fn run_app(app: AndroidApp) -> Result<(), Box<dyn Error>> {
+    let asset_manager = app.asset_manager();
+    let settings_dir = app.internal_data_path();
+    let settings = Settings::load(settings_dir.as_deref());
 
-fn play_background_music_android(asset_manager: AssetManager) -> Result<(), Box<dyn Error>> {
-    let music_bytes = load_asset_bytes("music.mp3", asset_manager);
-    let music_cursor = Cursor::new(music_bytes);
-    let source = Decoder::new_looped(music_cursor)?;
+    let _ = APP.set(app);
 
-    let stream_handle = OutputStreamBuilder::open_default_stream()?;
-    let sink = Sink::connect_new(stream_handle.mixer());
+    let music = Music::new(move || load_background_music_android(&asset_manager), settings.music_volume);
 
-    sink.append(source);
+    logic::start_ui(settings, settings_dir, music)
+}
 
-    sink.sleep_until_end();
+fn load_background_music_android(asset_manager: &AssetManager) -> Result<LoopedDecoder<Cursor<Vec<u8>>>, Box<dyn Error>> {
+    let music_bytes = load_asset_bytes("music.mp3", asset_manager);
+    let music_cursor = Cursor::new(music_bytes);
 
-    Ok(())
+    Ok(Decoder::new_looped(music_cursor)?)
 }
 
-fn load_asset_bytes(name: &str, mgr: AssetManager) -> Vec<u8> {
+fn load_asset_bytes(name: &str, mgr: &AssetManager) -> Vec<u8> {
     let mut asset = mgr.open(&CString::new(name).unwrap()).expect("asset dont exist");
     let mut buf: Vec<u8> = Vec::new();
     let _ = asset.read_to_end(&mut buf);
     buf
 }
+
+//INFO: an ACTION_SEND intent with the text in it, the chooser lets the user pick where it goes
+pub fn share_text(name: &str, mime_type: &str, text: &str) -> Result<(), Box<dyn Error>> {
+    let app = APP.get().ok_or("the app is not running")?;
+    let vm = unsafe { JavaVM::from_raw(app.vm_as_ptr().cast()) }?;
+    let mut env = vm.attach_current_thread()?;
+    let activity = unsafe { JObject::from_raw(app.activity_as_ptr().cast()) };
+
+    //INFO: the frame frees the local references, this thread stays attached and would keep them
+    let shared = env.with_local_frame(16, |env| -> jni::errors::Result<()> {
+        let action = env.new_string("android.intent.action.SEND")?;
+        let intent = env.new_object("android/content/Intent", "(Ljava/lang/String;)V", &[(&action).into()])?;
+
+        let mime_type = env.new_string(mime_type)?;
+        env.call_method(&intent, "setType", "(Ljava/lang/String;)Landroid/content/Intent;", &[(&mime_type).into()])?;
+
+        for (key, value) in [("android.intent.extra.TITLE", name), ("android.intent.extra.SUBJECT", name), ("android.intent.extra.TEXT", text)] {
+            let key = env.new_string(key)?;
+            let value = env.new_string(value)?;
+            env.call_method(&intent, "putExtra", "(Ljava/lang/String;Ljava/lang/String;)Landroid/content/Intent;", &[(&key).into(), (&value).into()])?;
+        }
+
+        let title = env.new_string(name)?;
+        let chooser = env
+            .call_static_method("android/content/Intent", "createChooser", "(Landroid/content/Intent;Ljava/lang/CharSequence;)Landroid/content/Intent;", &[(&intent).into(), (&title).into()])?
+            .l()?;
+        env.call_method(&activity, "startActivity", "(Landroid/content/Intent;)V", &[(&chooser).into()])?;
+
+        Ok(())
+    });
+
+    //INFO: a Java exception stays pending until cleared, the next call would fail on it
+    if shared.is_err() && env.exception_check().unwrap_or(false) {
+        let _ = env.exception_clear();
+    }
+
+    Ok(shared?)
+}
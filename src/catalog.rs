@@ -0,0 +1,54 @@
+use std::{error::Error, fs, path::{Path, PathBuf}};
+
+use calcularoty_core::cores::{parse_catalog, Core, BUNDLED_CATALOG};
+
+const CATALOG_FILE: &str = "cores.conf";
+
+pub fn catalog_path(dir: Option<&Path>) -> Option<PathBuf> {
+	dir.map(|dir| dir.join(CATALOG_FILE))
+}
+
+//INFO: the cores from the user's catalog, written out from the bundled one on first use so there is
+//INFO: a file to edit; a broken file falls back to the bundled catalog, the note says what happened
+pub fn load_catalog(dir: Option<&Path>) -> (Vec<Core>, String) {
+	//INFO: the bundled catalog is checked by the tests, it always parses
+	let bundled = || parse_catalog(BUNDLED_CATALOG).unwrap_or_default();
+
+	let Some(path) = catalog_path(dir) else {
+		return (bundled(), "bundled catalog, there is no config folder to keep an editable copy in".to_string());
+	};
+
+	match fs::read_to_string(&path) {
+		Ok(text) => match parse_catalog(&text) {
+			Ok(cores) => {
+				let note = format!("{} cores from {}", cores.len(), path.display());
+				(cores, note)
+			}
+			Err(error) => (bundled(), format!("{}: {error}, using the bundled catalog", path.display())),
+		},
+		Err(_) => {
+			let written = path.parent().map_or(Ok(()), fs::create_dir_all).and_then(|_| fs::write(&path, BUNDLED_CATALOG));
+
+			match written {
+				Ok(()) => (bundled(), format!("bundled catalog written to {}, edit it here or in the file", path.display())),
+				Err(error) => (bundled(), format!("bundled catalog, {} could not be written: {error}", path.display())),
+			}
+		}
+	}
+}
+
+//INFO: the text the editor starts from, the bundled catalog until the file is there
+pub fn read_catalog(dir: Option<&Path>) -> String {
+	catalog_path(dir).and_then(|path| fs::read_to_string(path).ok()).unwrap_or_else(|| BUNDLED_CATALOG.to_string())
+}
+
+//INFO: only a catalog that parses is written, a typo in the editor must not cost the file
+pub fn save_catalog(dir: Option<&Path>, text: &str) -> Result<(), Box<dyn Error>> {
+	parse_catalog(text)?;
+
+	let path = catalog_path(dir).ok_or("there is no config folder to keep the catalog in")?;
+	path.parent().map_or(Ok(()), fs::create_dir_all)?;
+	fs::write(&path, text)?;
+
+	Ok(())
+}
@@ -0,0 +1,486 @@
+use calcularoty_core::consts::{FARAD_BASE_TYPE, HENRY_BASE_TYPE, HERTZ_BASE_TYPE, METER_BASE_TYPE, OHM_BASE_TYPE, SIEMENS_BASE_TYPE, SQUARE_METER_BASE_TYPE};
+use calcularoty_core::calculations::*;
+use calcularoty_core::coil::{whole_turns, CoilGeometry, CoilShape};
+use calcularoty_core::cores::{al_inductance, al_turns};
+use calcularoty_core::capacitor::*;
+use calcularoty_core::conversions::*;
+use calcularoty_core::precision::{set_precision, set_rounding_mode, ROUNDING_MODES};
+use calcularoty_core::complex::ComplexExt;
+use calcularoty_core::formatting::NumberStyle;
+use calcularoty_core::network::Network;
+use calcularoty_core::sweep::{sweep_frequencies, sweep_rlc, SweepScale};
+use calcularoty_core::types::*;
+use calcularoty_core::utils::get_unit_group;
+
+use crate::catalog::load_catalog;
+use crate::settings::config_dir;
+
+use std::{collections::HashMap, error::Error};
+
+//INFO: the command, its "--name value" options and its bare "--flag" switches
+type Args = (String, HashMap<String, String>, Vec<String>);
+
+const USAGE: &str = "\
+usage:
+  calcularoty lc  --l <value> --c <value> --out <unit>
+  calcularoty lc  --c <value> --f <value> --out <unit>
+  calcularoty lc  --l <value> --f <value> --out <unit>
+  calcularoty imp --l <value> --c <value> --f <value> [--r <value>] [--series | --parallel]
+  calcularoty net --network <network> --f <value>
+  calcularoty sweep --l <value> --c <value> --start <value> --stop <value> [--points <n>] [--scale linear|log] [--r <value>] [--series | --parallel]
+  calcularoty coil --diameter <value> --wire <value> [--length <value>] [--shape single|multi|spiral] --turns <n>
+  calcularoty coil --diameter <value> --wire <value> [--length <value>] [--shape single|multi|spiral] --l <value>
+  calcularoty core (--al <value> | --core <name>) --turns <n>
+  calcularoty core (--al <value> | --core <name>) --l <value>
+  calcularoty cores
+  calcularoty cap [--shape plate] (--er <value> | --dielectric <name>) --area <value> --separation <value>
+  calcularoty cap --shape coax (--er <value> | --dielectric <name>) --inner <value> --outer <value> --length <value>
+  calcularoty cap ... --c <value>, leaving out the one dimension to solve for
+
+values take an optional prefix and unit, e.g. 10mH, 100n, 4k7Hz, 50Ω
+--out 'Auto Hz', 'Auto F' or 'Auto H' picks the engineering prefix
+one of --l, --c or --f may be left out on imp and is derived from the other two; circuits are
+series unless --parallel is given
+sweep prints f, |Z|, phase, X_L and X_C at each point, 50 points on a log scale by default
+networks join parts with + (series) and | (parallel), e.g. '50Ω + (10mH | 100nF)'
+coil sizes take a unit, --wire 0.5mm, a bare number is in m; --diameter is the former
+or the inner spiral diameter, a single layer without --length is wound close; with --l the
+turns for that inductance are worked out
+core takes A_L, the inductance of one turn, e.g. --al 4.9nH, or a core from the catalog, which
+'cores' lists along with where its editable file is
+cap areas take their unit, --area 100mm², a bare number is in m²; --dielectric takes a name such as PTFE or FR-4,
+εr defaults to 1; with --c the one left out of --separation and --area, or --length and
+--outer, is worked out
+--precision <bits> and --rounding <mode> set the working precision, default 1024 bits, ToEven
+--format '6 sig', '4 dp', 'sci 6', 'eng 6' or 'plain' sets how results are written, default '10 sig'";
+
+pub fn run(args: Vec<String>) -> Result<(), Box<dyn Error>> {
+	let (command, options, flags) = parse_args(&args)?;
+
+	if let Some(bits) = options.get("precision") {
+		set_precision(bits.parse().map_err(|_| format!("'{bits}' is not a number of bits"))?);
+	}
+	if let Some(mode) = options.get("rounding")
+		&& !set_rounding_mode(mode)
+	{
+		let modes = ROUNDING_MODES.iter().map(|(name, _)| *name).collect::<Vec<&str>>().join(", ");
+		return Err(format!("unknown rounding mode '{mode}', expected one of {modes}").into());
+	}
+
+	match command.as_str() {
+		"lc" => run_lc(&options),
+		"imp" => run_imp(&options, &flags),
+		"net" => run_net(&options),
+		"sweep" => run_sweep(&options, &flags),
+		"coil" => run_coil(&options),
+		"core" => run_core(&options),
+		"cores" => run_cores(),
+		"cap" => run_cap(&options),
+		"help" | "--help" | "-h" => {
+			println!("{USAGE}");
+			Ok(())
+		}
+		_ => Err(format!("unknown command '{command}'\n{USAGE}").into()),
+	}
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Box<dyn Error>> {
+	let mut iter = args.iter();
+	let command = iter.next().ok_or(USAGE)?.clone();
+
+	let mut options = HashMap::new();
+	let mut flags = Vec::new();
+
+	while let Some(arg) = iter.next() {
+		let name = arg.strip_prefix("--").ok_or(format!("unexpected argument '{arg}'\n{USAGE}"))?;
+
+		match name {
+			"parallel" | "series" => flags.push(name.to_string()),
+			_ => {
+				let value = iter.next().ok_or(format!("missing value for '--{name}'"))?;
+				options.insert(name.to_string(), value.clone());
+			}
+		}
+	}
+
+	Ok((command, options, flags))
+}
+
+fn run_lc(options: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+	let style = output_style(options)?;
+	let output_type = options.get("out").ok_or("missing '--out <unit>'")?;
+	let output_group = get_unit_group(output_type);
+
+	if ![UnitType::Hertz, UnitType::Farad, UnitType::Henry].contains(&output_group) {
+		return Err(format!("'{output_type}' is not a frequency, capacitance or inductance unit").into());
+	}
+
+	let inputs = ["l", "c", "f"]
+		.iter()
+		.filter_map(|name| options.get(*name).map(|value| (*name, value)))
+		.collect::<Vec<_>>();
+
+	if inputs.len() != 2 {
+		return Err(format!("lc takes exactly two of --l, --c and --f\n{USAGE}").into());
+	}
+
+	let input1_group = expected_group(inputs[0].0);
+	let input2_group = expected_group(inputs[1].0);
+
+	let input1 = parse_base_value(inputs[0].1, input1_group)?;
+	let input2 = parse_base_value(inputs[1].1, input2_group)?;
+
+	let result_base = if input1_group == output_group {
+		input1
+	} else if input2_group == output_group {
+		input2
+	} else {
+		calculate_lc(input1, input2, input1_group, output_group)?
+	};
+
+	let (result, auto_label) = convert_for_output(result_base, &output_group, output_type)?;
+
+	println!("{} {}", result.to_styled_string(style), auto_label.unwrap_or(output_type.as_str()));
+
+	Ok(())
+}
+
+fn run_imp(options: &HashMap<String, String>, flags: &[String]) -> Result<(), Box<dyn Error>> {
+	let style = output_style(options)?;
+	let l = options.get("l").map(|value| parse_base_value(value, UnitType::Henry)).transpose()?;
+	let c = options.get("c").map(|value| parse_base_value(value, UnitType::Farad)).transpose()?;
+	let f = options.get("f").map(|value| parse_base_value(value, UnitType::Hertz)).transpose()?;
+	let r = options.get("r").map(|value| parse_base_value(value, UnitType::Ohm)).transpose()?;
+
+	let (l, c, f) = match (l, c, f) {
+		(Some(l), Some(c), Some(f)) => (l, c, f),
+		(None, Some(c), Some(f)) => (cf0_to_l(c.clone(), f.clone()), c, f),
+		(Some(l), None, Some(f)) => (l.clone(), lf0_to_c(l, f.clone()), f),
+		(Some(l), Some(c), None) => (l.clone(), c.clone(), lc_to_f0(l, c)),
+		_ => return Err(format!("imp takes at least two of --l, --c and --f\n{USAGE}").into()),
+	};
+
+	let parallel = is_parallel(flags)?;
+	let omega = get_omega(f.clone());
+
+	let (result, resonance) = match parallel {
+		false => (
+			calculate_impedance_series(r.clone(), l.clone(), c.clone(), omega),
+			calculate_resonance_series(r, l.clone(), c.clone()),
+		),
+		true => (
+			calculate_impedance_parallel(r.clone(), l.clone(), c.clone(), omega),
+			calculate_resonance_parallel(r, l.clone(), c.clone()),
+		),
+	};
+
+	println!("circuit: {}", if parallel { "parallel" } else { "series" });
+	println!("L: {} {HENRY_BASE_TYPE}", l.to_styled_string(style));
+	println!("C: {} {FARAD_BASE_TYPE}", c.to_styled_string(style));
+	println!("f: {} {HERTZ_BASE_TYPE}", f.to_styled_string(style));
+	println!("|Z|: {} {OHM_BASE_TYPE}", result.magnitude().to_styled_string(style));
+	println!("R: {} {OHM_BASE_TYPE}", result.impedance.re.to_styled_string(style));
+	println!("X: {} {OHM_BASE_TYPE}", result.impedance.im.to_styled_string(style));
+	println!("phase: {} °", result.phase().to_degrees().to_styled_string(style));
+	println!("|Y|: {} {SIEMENS_BASE_TYPE}", result.admittance_magnitude().to_styled_string(style));
+	println!("X_L: {} {OHM_BASE_TYPE}", result.inductive_reactance.to_styled_string(style));
+	println!("X_C: {} {OHM_BASE_TYPE}", result.capacitive_reactance.to_styled_string(style));
+	println!("f0: {} {HERTZ_BASE_TYPE}", resonance.resonant_frequency.to_styled_string(style));
+	println!("Z0: {} {OHM_BASE_TYPE}", resonance.characteristic_impedance.to_styled_string(style));
+	println!("Q: {}", resonance.quality_factor.to_styled_string(style));
+	println!("damping: {}", resonance.damping_ratio.to_styled_string(style));
+	println!("BW: {} {HERTZ_BASE_TYPE}", resonance.bandwidth.to_styled_string(style));
+	println!("f1: {} {HERTZ_BASE_TYPE}", resonance.lower_frequency.to_styled_string(style));
+	println!("f2: {} {HERTZ_BASE_TYPE}", resonance.upper_frequency.to_styled_string(style));
+
+	Ok(())
+}
+
+fn run_net(options: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+	let style = output_style(options)?;
+
+	let network: Network = options.get("network").ok_or("missing '--network <network>'")?.parse()?;
+	let f = parse_base_value(options.get("f").ok_or("missing '--f <value>'")?, UnitType::Hertz)?;
+
+	let omega = get_omega(f.clone());
+	let impedance = network.impedance(&omega);
+
+	println!("network: {network}");
+	println!("f: {} {HERTZ_BASE_TYPE}", f.to_styled_string(style));
+	println!("|Z|: {} {OHM_BASE_TYPE}", impedance.magnitude().to_styled_string(style));
+	println!("R: {} {OHM_BASE_TYPE}", impedance.re.to_styled_string(style));
+	println!("X: {} {OHM_BASE_TYPE}", impedance.im.to_styled_string(style));
+	println!("phase: {} °", impedance.argument().to_degrees().to_styled_string(style));
+	println!("|Y|: {} {SIEMENS_BASE_TYPE}", network.admittance(&omega).magnitude().to_styled_string(style));
+
+	Ok(())
+}
+
+fn run_sweep(options: &HashMap<String, String>, flags: &[String]) -> Result<(), Box<dyn Error>> {
+	let style = output_style(options)?;
+	let l = parse_base_value(options.get("l").ok_or("missing '--l <value>'")?, UnitType::Henry)?;
+	let c = parse_base_value(options.get("c").ok_or("missing '--c <value>'")?, UnitType::Farad)?;
+	let r = options.get("r").map(|value| parse_base_value(value, UnitType::Ohm)).transpose()?;
+	let start = parse_base_value(options.get("start").ok_or("missing '--start <value>'")?, UnitType::Hertz)?;
+	let stop = parse_base_value(options.get("stop").ok_or("missing '--stop <value>'")?, UnitType::Hertz)?;
+
+	let points = match options.get("points") {
+		Some(points) => points.parse().map_err(|_| format!("'{points}' is not a number of points"))?,
+		None => 50,
+	};
+	let scale: SweepScale = match options.get("scale") {
+		Some(scale) => scale.parse()?,
+		None => SweepScale::default(),
+	};
+
+	let parallel = is_parallel(flags)?;
+	let frequencies = sweep_frequencies(start, stop, points, scale)?;
+
+	println!("f ({HERTZ_BASE_TYPE})\t|Z| ({OHM_BASE_TYPE})\tphase (°)\tX_L ({OHM_BASE_TYPE})\tX_C ({OHM_BASE_TYPE})");
+
+	for point in sweep_rlc(r, l, c, parallel, &frequencies) {
+		println!(
+			"{}\t{}\t{}\t{}\t{}",
+			point.frequency.to_styled_string(style),
+			point.impedance.to_styled_string(style),
+			point.phase.to_degrees().to_styled_string(style),
+			point.inductive_reactance.to_styled_string(style),
+			point.capacitive_reactance.to_styled_string(style),
+		);
+	}
+
+	Ok(())
+}
+
+fn run_coil(options: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+	let style = output_style(options)?;
+	let shape: CoilShape = match options.get("shape") {
+		Some(shape) => shape.parse()?,
+		None => CoilShape::default(),
+	};
+
+	let geometry = CoilGeometry {
+		shape,
+		diameter: parse_base_value(options.get("diameter").ok_or("missing '--diameter <value>'")?, UnitType::Meter)?,
+		length: options.get("length").map(|value| parse_base_value(value, UnitType::Meter)).transpose()?,
+		wire: parse_base_value(options.get("wire").ok_or("missing '--wire <value>'")?, UnitType::Meter)?,
+	};
+
+	let turns = match (options.get("turns"), options.get("l")) {
+		(Some(turns), None) => turns.parse::<BFloat>()?,
+		(None, Some(target)) => {
+			let exact = geometry.turns_for(&parse_base_value(target, UnitType::Henry)?)?;
+			println!("exact turns: {}", exact.to_styled_string(style));
+
+			BFloat::from(whole_turns(&exact))
+		}
+		_ => return Err(format!("coil takes one of --turns and --l\n{USAGE}").into()),
+	};
+
+	let coil = geometry.coil(&turns)?;
+
+	println!("shape: {shape}");
+	println!("turns: {}", coil.turns.to_styled_string(style));
+	println!("L: {} {HENRY_BASE_TYPE}", coil.inductance.to_styled_string(style));
+	println!("length: {} {METER_BASE_TYPE}", coil.length.to_styled_string(style));
+	println!("outer diameter: {} {METER_BASE_TYPE}", coil.outer_diameter.to_styled_string(style));
+	println!("wire length: {} {METER_BASE_TYPE}", coil.wire_length.to_styled_string(style));
+
+	Ok(())
+}
+
+fn run_core(options: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+	let style = output_style(options)?;
+
+	let al = match (options.get("al"), options.get("core")) {
+		(Some(al), None) => parse_base_value(al, UnitType::Henry)?,
+		(None, Some(name)) => {
+			let (cores, note) = load_catalog(config_dir().as_deref());
+			let core = cores
+				.into_iter()
+				.find(|core| core.name.eq_ignore_ascii_case(name))
+				.ok_or(format!("'{name}' is not in the core catalog ({note})"))?;
+
+			println!("core: {} ({})", core.name, core.kind);
+			core.al
+		}
+		_ => return Err(format!("core takes one of --al and --core\n{USAGE}").into()),
+	};
+
+	let turns = match (options.get("turns"), options.get("l")) {
+		(Some(turns), None) => turns.parse::<BFloat>()?,
+		(None, Some(target)) => {
+			let exact = al_turns(&al, &parse_base_value(target, UnitType::Henry)?)?;
+			println!("exact turns: {}", exact.to_styled_string(style));
+
+			BFloat::from(whole_turns(&exact))
+		}
+		_ => return Err(format!("core takes one of --turns and --l\n{USAGE}").into()),
+	};
+
+	println!("A_L: {} {HENRY_BASE_TYPE}", al.to_styled_string(style));
+	println!("turns: {}", turns.to_styled_string(style));
+	println!("L: {} {HENRY_BASE_TYPE}", al_inductance(&al, &turns)?.to_styled_string(style));
+
+	Ok(())
+}
+
+fn run_cores() -> Result<(), Box<dyn Error>> {
+	let (cores, note) = load_catalog(config_dir().as_deref());
+
+	println!("{note}");
+	println!("name\tkind\tA_L ({HENRY_BASE_TYPE})\tμ");
+
+	for core in cores {
+		let style = NumberStyle::Significant(4);
+		println!("{}\t{}\t{}\t{}", core.name, core.kind, core.al.to_styled_string(style), core.permeability.to_styled_string(style));
+	}
+
+	Ok(())
+}
+
+fn run_cap(options: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+	let style = output_style(options)?;
+	let shape: CapacitorShape = match options.get("shape") {
+		Some(shape) => shape.parse()?,
+		None => CapacitorShape::default(),
+	};
+
+	let permittivity = match (options.get("er"), options.get("dielectric")) {
+		(Some(permittivity), None) => permittivity.parse::<BFloat>()?,
+		(None, Some(name)) => dielectric(name).ok_or(format!("'{name}' is not in the dielectric table"))?,
+		(None, None) => BFloat::from(1),
+		_ => return Err(format!("cap takes one of --er and --dielectric\n{USAGE}").into()),
+	};
+
+	let meter = |name: &str| options.get(name).map(|value| parse_base_value(value, UnitType::Meter)).transpose();
+	let target = options.get("c").map(|value| parse_base_value(value, UnitType::Farad)).transpose()?;
+	let missing = |name: &str| format!("missing '--{name} <value>'");
+
+	println!("shape: {shape}");
+	println!("εr: {}", permittivity.to_styled_string(style));
+
+	let capacitance = match shape {
+		CapacitorShape::ParallelPlate => {
+			let area = options.get("area").map(|value| parse_base_value(value, UnitType::SquareMeter)).transpose()?;
+			let separation = meter("separation")?;
+
+			let (area, separation) = match (area, separation, target) {
+				(Some(area), None, Some(target)) => {
+					let separation = plate_separation(&area, &permittivity, &target)?;
+					println!("separation: {} {METER_BASE_TYPE}", separation.to_styled_string(style));
+					(area, separation)
+				}
+				(None, Some(separation), Some(target)) => {
+					let area = plate_area(&separation, &permittivity, &target)?;
+					println!("area: {} {SQUARE_METER_BASE_TYPE}", area.to_styled_string(style));
+					(area, separation)
+				}
+				(Some(area), Some(separation), None) => (area, separation),
+				(None, _, None) => return Err(missing("area").into()),
+				(_, None, None) => return Err(missing("separation").into()),
+				_ => return Err(format!("with --c leave out one of --area and --separation\n{USAGE}").into()),
+			};
+
+			plate_capacitance(&area, &separation, &permittivity)?
+		}
+		CapacitorShape::Coaxial => {
+			let inner = meter("inner")?.ok_or(missing("inner"))?;
+			let (outer, length) = match (meter("outer")?, meter("length")?, target) {
+				(Some(outer), None, Some(target)) => {
+					let length = coax_length(&inner, &outer, &permittivity, &target)?;
+					println!("length: {} {METER_BASE_TYPE}", length.to_styled_string(style));
+					(outer, length)
+				}
+				(None, Some(length), Some(target)) => {
+					let outer = coax_outer_diameter(&inner, &length, &permittivity, &target)?;
+					println!("outer diameter: {} {METER_BASE_TYPE}", outer.to_styled_string(style));
+					(outer, length)
+				}
+				(Some(outer), Some(length), None) => (outer, length),
+				(None, _, None) => return Err(missing("outer").into()),
+				(_, None, None) => return Err(missing("length").into()),
+				_ => return Err(format!("with --c leave out one of --outer and --length\n{USAGE}").into()),
+			};
+
+			println!("Z0: {} {OHM_BASE_TYPE}", coax_impedance(&inner, &outer, &permittivity)?.to_styled_string(style));
+			coax_capacitance(&inner, &outer, &length, &permittivity)?
+		}
+	};
+
+	println!("C: {} {FARAD_BASE_TYPE}", capacitance.to_styled_string(style));
+
+	Ok(())
+}
+
+//INFO: series is the default, --series only says so; both at once is a typo, not a choice
+fn is_parallel(flags: &[String]) -> Result<bool, Box<dyn Error>> {
+	let has = |name: &str| flags.iter().any(|flag| flag == name);
+
+	match (has("series"), has("parallel")) {
+		(true, true) => Err("--series and --parallel exclude each other".into()),
+		(_, parallel) => Ok(parallel),
+	}
+}
+
+fn output_style(options: &HashMap<String, String>) -> Result<NumberStyle, Box<dyn Error>> {
+	match options.get("format") {
+		Some(style) => Ok(style.parse()?),
+		None => Ok(NumberStyle::default()),
+	}
+}
+
+fn expected_group(name: &str) -> UnitType {
+	match name {
+		"l" => UnitType::Henry,
+		"c" => UnitType::Farad,
+		"f" => UnitType::Hertz,
+		"r" => UnitType::Ohm,
+		_ => UnitType::NotSelected,
+	}
+}
+
+//INFO: "10mH", "4u7", "100 nF", a bare number is in the base unit
+fn parse_base_value(value: &str, unit_type: UnitType) -> Result<BFloat, Box<dyn Error>> {
+	Ok(parse_to_base(value, &unit_type, unit_type.base_unit())?)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{is_parallel, parse_args, run};
+
+	fn args(text: &str) -> Vec<String> {
+		text.split_whitespace().map(str::to_string).collect()
+	}
+
+	#[test]
+	fn argument_parsing() {
+		let (command, options, flags) = parse_args(&args("imp --l 10m --c 100n --f 1k --parallel")).unwrap();
+		assert_eq!(command, "imp");
+		assert_eq!(options.get("l").map(String::as_str), Some("10m"));
+		assert_eq!(options.get("f").map(String::as_str), Some("1k"));
+		assert_eq!(flags, ["parallel"]);
+
+		assert!(!is_parallel(&args("")).unwrap());
+		assert!(!is_parallel(&args("series")).unwrap());
+		assert!(is_parallel(&args("parallel")).unwrap());
+		assert!(is_parallel(&args("series parallel")).is_err());
+
+		assert!(parse_args(&[]).is_err());
+		assert!(parse_args(&args("lc 10m")).is_err());
+		assert!(parse_args(&args("lc --l")).unwrap_err().to_string().contains("missing value for '--l'"));
+	}
+
+	#[test]
+	fn command_errors() {
+		assert!(run(args("help")).is_ok());
+		assert!(run(args("lc --l 10m --c 100n --out Hz")).is_ok());
+
+		assert!(run(args("frobnicate")).unwrap_err().to_string().starts_with("unknown command 'frobnicate'"));
+		assert!(run(args("lc --l 10m --c 100n")).is_err());
+		assert!(run(args("lc --l 10m --out Hz")).is_err());
+		assert!(run(args("lc --l 10x --c 100n --out Hz")).is_err());
+		assert!(run(args("imp --l 10m --c 100n --f 1k --series --parallel")).is_err());
+		assert!(run(args("lc --l 10m --c 100n --out Hz --rounding Sideways")).is_err());
+	}
+}
@@ -0,0 +1,295 @@
+use std::{error::Error, str::FromStr};
+#[cfg(not(target_os = "android"))]
+use std::{fs, path::{Path, PathBuf}};
+
+use calcularoty_core::consts::AUTO_UNIT;
+use calcularoty_core::types::UnitType;
+use calcularoty_core::utils::get_unit_group;
+
+use crate::history::HistoryEntry;
+
+pub const EXPORT_FORMATS: [&str; 2] = ["CSV", "JSON"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+	Csv,
+	Json,
+}
+
+impl FromStr for ExportFormat {
+	type Err = String;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim().to_lowercase().as_str() {
+			"csv" => Ok(ExportFormat::Csv),
+			"json" => Ok(ExportFormat::Json),
+			_ => Err(format!("'{s}' is not an export format, CSV or JSON")),
+		}
+	}
+}
+
+impl ExportFormat {
+	pub fn extension(&self) -> &'static str {
+		match self {
+			ExportFormat::Csv => "csv",
+			ExportFormat::Json => "json",
+		}
+	}
+
+	#[cfg(target_os = "android")]
+	pub fn mime_type(&self) -> &'static str {
+		match self {
+			ExportFormat::Csv => "text/csv",
+			ExportFormat::Json => "application/json",
+		}
+	}
+}
+
+//INFO: one value of an export, inputs as typed and outputs as shown
+struct Row {
+	section: &'static str,
+	name: String,
+	value: String,
+	unit: String,
+}
+
+impl Row {
+	fn new(section: &'static str, name: &str, value: &str, unit: &str) -> Row {
+		//INFO: Auto outputs carry their prefixed unit at the end of the value, split it back out
+		let (value, unit) = match unit.starts_with(AUTO_UNIT) {
+			true => value.rsplit_once(' ').unwrap_or((value, "")),
+			false => (value, unit),
+		};
+
+		Row {
+			section,
+			name: name.to_string(),
+			value: value.trim().to_string(),
+			unit: unit.to_string(),
+		}
+	}
+}
+
+fn rows(entry: &HistoryEntry) -> Vec<Row> {
+	let outputs = |outputs: &[(String, String, String)]| {
+		outputs
+			.iter()
+			.map(|(name, value, unit)| Row::new("output", name, value, unit))
+			.collect::<Vec<Row>>()
+	};
+
+	match entry {
+		HistoryEntry::Lc(record) => vec![
+			Row::new("input", quantity_name(&record.input1_unit), &record.input1_text, &record.input1_unit),
+			Row::new("input", quantity_name(&record.input2_unit), &record.input2_text, &record.input2_unit),
+			Row::new("output", quantity_name(&record.output_unit), &record.result, &record.output_unit),
+		],
+		HistoryEntry::Imp(record) => {
+			let circuit = match record.circuit {
+				0 => "series",
+				_ => "parallel",
+			};
+
+			let mut rows = vec![Row::new("input", "circuit", circuit, "")];
+
+			//INFO: a value left empty was derived, it shows up among the outputs instead
+			rows.extend(
+				[
+					("L", &record.inductance, &record.l_unit),
+					("C", &record.capacitance, &record.c_unit),
+					("f", &record.frequency, &record.f_unit),
+					("R", &record.resistance, &record.r_unit),
+				]
+				.into_iter()
+				.filter(|(_, text, _)| !text.trim().is_empty())
+				.map(|(name, text, unit)| Row::new("input", name, text, unit)),
+			);
+
+			rows.extend(outputs(&record.outputs));
+			rows
+		}
+		HistoryEntry::Net(record) => {
+			let mut rows = vec![
+				Row::new("input", "network", &record.network, ""),
+				Row::new("input", "f", &record.frequency, &record.f_unit),
+			];
+
+			rows.extend(outputs(&record.outputs));
+			rows
+		}
+	}
+}
+
+fn quantity_name(unit: &str) -> &'static str {
+	match get_unit_group(unit) {
+		UnitType::Henry => "L",
+		UnitType::Farad => "C",
+		UnitType::Hertz => "f",
+		UnitType::Ohm => "R",
+		UnitType::Siemens => "G",
+		UnitType::Meter => "length",
+		UnitType::SquareMeter => "area",
+		UnitType::NotSelected => "value",
+	}
+}
+
+/// The inputs, units and outputs of one calculation as the text of an export file.
+pub fn export_text(entry: &HistoryEntry, format: ExportFormat) -> String {
+	let rows = rows(entry);
+
+	match format {
+		ExportFormat::Csv => {
+			let title = entry.title();
+			let mut text = String::from("calculation,section,name,value,unit\n");
+
+			for row in &rows {
+				let fields = [title.as_str(), row.section, row.name.as_str(), row.value.as_str(), row.unit.as_str()].map(csv_field);
+				text.push_str(&fields.join(","));
+				text.push('\n');
+			}
+
+			text
+		}
+		ExportFormat::Json => {
+			let section = |name: &str| {
+				rows
+					.iter()
+					.filter(|row| row.section == name)
+					.map(|row| format!(
+						"    {{ \"name\": {}, \"value\": {}, \"unit\": {} }}",
+						json_string(&row.name), json_string(&row.value), json_string(&row.unit),
+					))
+					.collect::<Vec<String>>()
+					.join(",\n")
+			};
+
+			format!(
+				"{{\n  \"calculation\": {},\n  \"inputs\": [\n{}\n  ],\n  \"outputs\": [\n{}\n  ]\n}}\n",
+				json_string(&entry.title()),
+				section("input"),
+				section("output"),
+			)
+		}
+	}
+}
+
+/// The name an export of `entry` is offered under.
+pub fn export_file_name(entry: &HistoryEntry, format: ExportFormat) -> String {
+	let page = match entry {
+		HistoryEntry::Lc(_) => "lc",
+		HistoryEntry::Imp(_) => "imp",
+		HistoryEntry::Net(_) => "net",
+	};
+
+	format!("calcularoty_{page}.{}", format.extension())
+}
+
+/// Asks where to save the export of `entry` and writes it there, returns what happened.
+#[cfg(not(target_os = "android"))]
+pub fn export_entry(entry: &HistoryEntry, format: ExportFormat) -> Result<String, Box<dyn Error>> {
+	let picked = rfd::FileDialog::new()
+		.set_directory(export_dir())
+		.set_file_name(export_file_name(entry, format))
+		.add_filter(format.extension().to_uppercase(), &[format.extension()])
+		.save_file();
+
+	match picked {
+		Some(path) => Ok(format!("saved to {}", write_export(entry, format, &path)?.display())),
+		None => Ok("export cancelled".to_string()),
+	}
+}
+
+//INFO: the share sheet hands the text to a file manager, a drive or a mail, no storage permission needed
+/// Opens the share sheet with the export of `entry`, returns what happened.
+#[cfg(target_os = "android")]
+pub fn export_entry(entry: &HistoryEntry, format: ExportFormat) -> Result<String, Box<dyn Error>> {
+	let name = export_file_name(entry, format);
+
+	crate::android::share_text(&name, format.mime_type(), &export_text(entry, format))?;
+
+	Ok(format!("{name} handed to the share sheet"))
+}
+
+/// Writes the export next to `path`, with the extension of `format`, and returns where it went.
+#[cfg(not(target_os = "android"))]
+pub fn write_export(entry: &HistoryEntry, format: ExportFormat, path: &Path) -> Result<PathBuf, Box<dyn Error>> {
+	if path.as_os_str().is_empty() {
+		return Err("choose a file to export to".into());
+	}
+
+	let path = path.with_extension(format.extension());
+
+	if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+		fs::create_dir_all(dir)?;
+	}
+
+	fs::write(&path, export_text(entry, format))?;
+
+	Ok(path)
+}
+
+//INFO: quoted only when needed, quotes inside are doubled
+fn csv_field(field: &str) -> String {
+	match field.contains([',', '"', '\n', '\r']) {
+		true => format!("\"{}\"", field.replace('"', "\"\"")),
+		false => field.to_string(),
+	}
+}
+
+fn json_string(text: &str) -> String {
+	let mut escaped = String::from("\"");
+
+	for c in text.chars() {
+		match c {
+			'"' => escaped.push_str("\\\""),
+			'\\' => escaped.push_str("\\\\"),
+			'\n' => escaped.push_str("\\n"),
+			'\t' => escaped.push_str("\\t"),
+			c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+			c => escaped.push(c),
+		}
+	}
+
+	escaped.push('"');
+	escaped
+}
+
+//INFO: where the save dialog opens, the user's Documents folder, or their home when there is none
+#[cfg(not(target_os = "android"))]
+fn export_dir() -> PathBuf {
+	let home = ["HOME", "USERPROFILE"]
+		.iter()
+		.find_map(|name| std::env::var_os(name).filter(|value| !value.is_empty()))
+		.map(PathBuf::from);
+
+	match home {
+		Some(home) if home.join("Documents").is_dir() => home.join("Documents"),
+		Some(home) => home,
+		None => PathBuf::from("."),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{csv_field, json_string};
+
+	#[test]
+	fn csv_escaping() {
+		assert_eq!(csv_field("4.7 μH"), "4.7 μH");
+		assert_eq!(csv_field("L || C"), "L || C");
+		assert_eq!(csv_field("1,5"), "\"1,5\"");
+		assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+		assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+		assert_eq!(csv_field("two\r\nlines"), "\"two\r\nlines\"");
+		assert_eq!(csv_field(""), "");
+	}
+
+	#[test]
+	fn json_escaping() {
+		assert_eq!(json_string("2.2 kΩ"), "\"2.2 kΩ\"");
+		assert_eq!(json_string("a \"b\", c"), "\"a \\\"b\\\", c\"");
+		assert_eq!(json_string("C:\\dir"), "\"C:\\\\dir\"");
+		assert_eq!(json_string("one\ntwo\tthree"), "\"one\\ntwo\\tthree\"");
+		assert_eq!(json_string("\u{1}\r\u{7f}"), "\"\\u0001\\u000d\\u007f\"");
+		assert_eq!(json_string(""), "\"\"");
+	}
+}
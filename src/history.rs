@@ -0,0 +1,116 @@
+use calcularoty_core::consts::AUTO_UNIT;
+
+//INFO: one finished calculation, kept as the text the user typed so it can be put back into the page
+
+#[derive(Clone, Debug)]
+pub struct LcRecord {
+	pub input1_text: String,
+	pub input1_unit: String,
+	pub input2_text: String,
+	pub input2_unit: String,
+	pub output_unit: String,
+	pub result: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct ImpRecord {
+	pub inductance: String,
+	pub capacitance: String,
+	pub frequency: String,
+	pub resistance: String,
+	pub l_unit: String,
+	pub c_unit: String,
+	pub f_unit: String,
+	pub r_unit: String,
+	pub circuit: i32,
+	//INFO: (name, value, unit), Auto outputs carry their prefix in the value
+	pub outputs: Vec<(String, String, String)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct NetRecord {
+	pub network: String,
+	pub frequency: String,
+	pub f_unit: String,
+	pub outputs: Vec<(String, String, String)>,
+}
+
+#[derive(Clone, Debug)]
+pub enum HistoryEntry {
+	Lc(LcRecord),
+	Imp(ImpRecord),
+	Net(NetRecord),
+}
+
+pub const LC_PAGE: i32 = 1;
+pub const IMP_PAGE: i32 = 2;
+pub const NET_PAGE: i32 = 5;
+
+impl HistoryEntry {
+	pub fn page(&self) -> i32 {
+		match self {
+			HistoryEntry::Lc(_) => LC_PAGE,
+			HistoryEntry::Imp(_) => IMP_PAGE,
+			HistoryEntry::Net(_) => NET_PAGE,
+		}
+	}
+
+	pub fn title(&self) -> String {
+		match self {
+			HistoryEntry::Lc(_) => "LC".to_string(),
+			HistoryEntry::Imp(record) => match record.circuit {
+				0 => "IMP Série".to_string(),
+				_ => "IMP Paralelo".to_string(),
+			},
+			HistoryEntry::Net(_) => "NET".to_string(),
+		}
+	}
+
+	pub fn summary(&self) -> String {
+		match self {
+			HistoryEntry::Lc(record) => format!(
+				"{} {}, {} {} → {}",
+				record.input1_text, record.input1_unit,
+				record.input2_text, record.input2_unit,
+				value_with_unit(&record.result, &record.output_unit),
+			),
+			HistoryEntry::Imp(record) => {
+				let inputs = [
+					("L", &record.inductance, &record.l_unit),
+					("C", &record.capacitance, &record.c_unit),
+					("f", &record.frequency, &record.f_unit),
+					("R", &record.resistance, &record.r_unit),
+				]
+				.iter()
+				.filter(|(_, text, _)| !text.trim().is_empty())
+				.map(|(name, text, unit)| format!("{name} {text} {unit}"))
+				.collect::<Vec<String>>()
+				.join(", ");
+
+				format!("{inputs} → {}", summarize_outputs(&record.outputs))
+			}
+			HistoryEntry::Net(record) => format!(
+				"{} at {} {} → {}",
+				record.network, record.frequency, record.f_unit,
+				summarize_outputs(&record.outputs),
+			),
+		}
+	}
+}
+
+fn summarize_outputs(outputs: &[(String, String, String)]) -> String {
+	outputs
+		.iter()
+		.take(2)
+		.map(|(name, value, unit)| format!("{name} {}", value_with_unit(value, unit)))
+		.collect::<Vec<String>>()
+		.join(", ")
+}
+
+//INFO: Auto outputs already end with their prefixed unit
+pub fn value_with_unit(value: &str, unit: &str) -> String {
+	match unit.starts_with(AUTO_UNIT) {
+		true => value.to_string(),
+		false => format!("{value} {unit}"),
+	}
+}
@@ -2,16 +2,17 @@
 
 mod android;
 mod utils;
-mod types;
-mod units;
+mod catalog;
+mod export;
+mod history;
+mod music;
+mod plot;
+mod settings;
 mod traits;
-mod calculations;
-mod consts;
-mod conversions;
 
 #[unsafe(no_mangle)]
 fn android_main(app: slint::android::AndroidApp) -> Result<(), Box<dyn std::error::Error>> {
     slint::android::init(app.clone())?;
 
-    android::run_app(app.asset_manager())
+    android::run_app(app)
 }
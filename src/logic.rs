@@ -1,29 +1,125 @@
-use crate::consts::{FARAD_BASE_TYPE, HENRY_BASE_TYPE, HERTZ_BASE_TYPE, OHM_BASE_TYPE};
-use crate::types::*;
+use calcularoty_core::consts::{AUTO_UNIT, FARAD_BASE_TYPE, HENRY_BASE_TYPE, HERTZ_BASE_TYPE};
+use calcularoty_core::coil::{whole_turns, CoilGeometry, CoilShape, COIL_SHAPES};
+use calcularoty_core::cores::{al_inductance, al_turns, Core};
+use calcularoty_core::capacitor::*;
+use calcularoty_core::types::*;
+use calcularoty_core::error::CalcError;
+use calcularoty_core::precision::*;
+use calcularoty_core::formatting::{NumberStyle, STYLE_PRESETS};
+use calcularoty_core::network::Network;
+use calcularoty_core::combinations::best_pairs;
+use calcularoty_core::eseries::{relative_error, ESeries, E_SERIES_NAMES};
+use calcularoty_core::montecarlo::{monte_carlo, Distribution, Rng, Statistics, Toleranced, DISTRIBUTIONS};
+use calcularoty_core::worstcase::{sensitivities, worst_case, Extremes};
+use calcularoty_core::sweep::{sweep_frequencies, sweep_rlc, SweepScale};
+use calcularoty_core::complex::ComplexExt;
 use crate::traits::MapToSharedStringVec;
+use crate::history::{HistoryEntry, ImpRecord, LcRecord, NetRecord};
+use crate::export::{export_entry, ExportFormat, EXPORT_FORMATS};
+use crate::music::Music;
+use crate::plot::{render_bode, render_histogram, PLOT_POINTS};
+use crate::settings::{CapSettings, CoilSettings, CoreSettings, Settings};
+use crate::catalog::{load_catalog, read_catalog, save_catalog};
 
-use crate::units::{*};
+use calcularoty_core::units::{*};
+use calcularoty_core::utils::get_unit_group;
 use crate::utils::{*};
-use crate::calculations::*;
-use crate::conversions::*;
+use calcularoty_core::calculations::*;
+use calcularoty_core::conversions::*;
 
-use std::str::FromStr;
-use std::{cell::RefCell, error::Error, rc::Rc};
+use std::{cell::RefCell, error::Error, path::{Path, PathBuf}, rc::Rc, thread};
 
-use slint::{SharedString, ToSharedString};
+use slint::{Image, Model, ModelRc, SharedString, StandardListViewItem, ToSharedString, VecModel, Weak};
 
 slint::include_modules!();
 
-pub fn start_ui() -> Result<(), Box<dyn Error>> {
+//INFO: which input of a page an error message is shown under
+type FieldError = (i32, SharedString);
+
+const NO_FIELD: i32 = -1;
+
+const LC_INPUT1_FIELD: i32 = 0;
+const LC_INPUT2_FIELD: i32 = 1;
+const LC_OUTPUT_FIELD: i32 = 2;
+const LC_PAIRS_FIELD: i32 = 3;
+const LC_TOLERANCE_FIELD: i32 = 4;
+
+//INFO: how many pairs the LC page lists
+const PAIR_LIMIT: usize = 10;
+
+const IMP_L_FIELD: i32 = 0;
+const IMP_C_FIELD: i32 = 1;
+const IMP_F_FIELD: i32 = 2;
+const IMP_R_FIELD: i32 = 3;
+const IMP_GENERAL_FIELD: i32 = 4;
+const IMP_SWEEP_START_FIELD: i32 = 5;
+const IMP_SWEEP_STOP_FIELD: i32 = 6;
+const IMP_SWEEP_FIELD: i32 = 7;
+const IMP_TOLERANCE_FIELD: i32 = 8;
+
+//INFO: what the IMP tolerance analysis reports, in the order imp_evaluate returns it
+const IMP_TOLERANCE_RESULTS: [(&str, UnitType); 2] = [("f0", UnitType::Hertz), ("|Z|", UnitType::Ohm)];
+
+const NET_NETWORK_FIELD: i32 = 0;
+const NET_F_FIELD: i32 = 1;
+const NET_GENERAL_FIELD: i32 = 2;
+
+const COIL_TURNS_FIELD: i32 = 0;
+const COIL_DIAMETER_FIELD: i32 = 1;
+const COIL_LENGTH_FIELD: i32 = 2;
+const COIL_WIRE_FIELD: i32 = 3;
+const COIL_TARGET_FIELD: i32 = 4;
+const COIL_GENERAL_FIELD: i32 = 5;
+
+const CORE_AL_FIELD: i32 = 0;
+const CORE_TURNS_FIELD: i32 = 1;
+const CORE_TARGET_FIELD: i32 = 2;
+const CORE_GENERAL_FIELD: i32 = 3;
+
+const CAP_PERMITTIVITY_FIELD: i32 = 0;
+const CAP_AREA_FIELD: i32 = 1;
+const CAP_SEPARATION_FIELD: i32 = 2;
+const CAP_INNER_FIELD: i32 = 3;
+const CAP_OUTER_FIELD: i32 = 4;
+const CAP_LENGTH_FIELD: i32 = 5;
+const CAP_TARGET_FIELD: i32 = 6;
+const CAP_GENERAL_FIELD: i32 = 7;
+
+//INFO: first in the dielectric combo, the εr typed in by hand
+const CUSTOM_DIELECTRIC: &str = "Custom";
+
+//INFO: the pages the core page sends its inductance to
+const LC_PAGE: i32 = 1;
+const IMP_PAGE: i32 = 2;
+
+pub fn start_ui(settings: Settings, settings_dir: Option<PathBuf>, music: Music) -> Result<(), Box<dyn Error>> {
 	let ui = MainWindow::new()?;
 
 	let input1_type = Rc::new(RefCell::new(UnitType::NotSelected));
 	let input2_type = Rc::new(RefCell::new(UnitType::NotSelected));
 
+	//INFO: the last L or C the LC page computed, what the pair search aims for
+	let lc_target: Rc<RefCell<Option<(BFloat, UnitType)>>> = Rc::new(RefCell::new(None));
+
+	let settings = Rc::new(RefCell::new(settings));
+	let settings_dir = Rc::new(settings_dir);
+
+	//INFO: the cores of the catalog file, and the last inductance the core page computed
+	let (cores, catalog_note) = load_catalog(settings_dir.as_deref());
+	let cores = Rc::new(RefCell::new(cores));
+	let core_inductance: Rc<RefCell<Option<BFloat>>> = Rc::new(RefCell::new(None));
+
+	let history: Rc<RefCell<Vec<HistoryEntry>>> = Rc::new(RefCell::new(Vec::new()));
+	let history_model: Rc<VecModel<HistoryItem>> = Rc::new(VecModel::default());
+
+	ui.set_history(ModelRc::from(history_model.clone()));
+
 	let hertz_units_shared: Vec<SharedString> = HERTZ_UNITS.to_shared_string_vec();
 	let farad_units_shared: Vec<SharedString> = FARAD_UNITS.to_shared_string_vec();
 	let henry_units_shared: Vec<SharedString> = HENRY_UNITS.to_shared_string_vec();
 	let ohm_units_shared: Vec<SharedString> = OHM_UNITS.to_shared_string_vec();
+	let siemens_units_shared: Vec<SharedString> = SIEMENS_UNITS.to_shared_string_vec();
+	let meter_units_shared: Vec<SharedString> = METER_UNITS.to_shared_string_vec();
 
 	let full_model = vec_to_model([hertz_units_shared.clone(), farad_units_shared.clone(), henry_units_shared.clone()].concat());
 	
@@ -35,12 +131,114 @@ pub fn start_ui() -> Result<(), Box<dyn Error>> {
 	//ui.set_lc_input2_model(full_model.clone());
 	//ui.set_lc_input3_model(full_model.clone());
 
+	let auto_shared = |units: &[&str]| units.iter().map(|unit| format!("{AUTO_UNIT} {unit}").to_shared_string()).collect::<Vec<SharedString>>();
+
 	ui.set_lc_model(full_model);
+	ui.set_lc_output_model(vec_to_model([auto_shared(&[HERTZ_BASE_TYPE, FARAD_BASE_TYPE, HENRY_BASE_TYPE]), hertz_units_shared.clone(), farad_units_shared.clone(), henry_units_shared.clone()].concat()));
+
+	ui.set_l_model(vec_to_model(henry_units_shared.clone()));
+	ui.set_c_model(vec_to_model(farad_units_shared.clone()));
+	ui.set_f_model(vec_to_model(hertz_units_shared.clone()));
+	ui.set_r_model(vec_to_model(ohm_units_shared.clone()));
+	ui.set_g_model(vec_to_model(siemens_units_shared.clone()));
+	ui.set_m_model(vec_to_model(meter_units_shared.clone()));
+
+	ui.set_format_model(vec_to_model(STYLE_PRESETS.iter().map(|style| style.to_shared_string()).collect()));
+
+	ui.set_f_out_model(vec_to_model([vec![AUTO_UNIT.to_shared_string()], hertz_units_shared].concat()));
+	ui.set_r_out_model(vec_to_model([vec![AUTO_UNIT.to_shared_string()], ohm_units_shared].concat()));
+	ui.set_g_out_model(vec_to_model([vec![AUTO_UNIT.to_shared_string()], siemens_units_shared].concat()));
+	ui.set_l_out_model(vec_to_model([vec![AUTO_UNIT.to_shared_string()], henry_units_shared].concat()));
+	ui.set_m_out_model(vec_to_model([vec![AUTO_UNIT.to_shared_string()], meter_units_shared].concat()));
+	ui.set_c_out_model(vec_to_model([vec![AUTO_UNIT.to_shared_string()], farad_units_shared].concat()));
+	ui.set_a_model(vec_to_model(SQUARE_METER_UNITS.to_shared_string_vec()));
+
+	ui.set_coil_shape_model(vec_to_model(COIL_SHAPES.iter().map(|shape| shape.to_shared_string()).collect()));
+	ui.set_core_model(vec_to_model(cores.borrow().iter().map(|core| core.name.to_shared_string()).collect()));
+	ui.set_core_catalog_text(catalog_note.into());
+
+	ui.set_cap_shape_model(vec_to_model(CAPACITOR_SHAPES.iter().map(|shape| shape.to_shared_string()).collect()));
+	ui.set_dielectric_model(vec_to_model([vec![CUSTOM_DIELECTRIC.to_shared_string()], DIELECTRICS.iter().map(|(name, _)| name.to_shared_string()).collect()].concat()));
+	ui.set_plate_unknown_model(vec_to_model(PLATE_UNKNOWNS.iter().map(|name| name.to_shared_string()).collect()));
+	ui.set_coax_unknown_model(vec_to_model(COAX_UNKNOWNS.iter().map(|name| name.to_shared_string()).collect()));
+
+	ui.set_distribution_model(vec_to_model(DISTRIBUTIONS.iter().map(|name| name.to_shared_string()).collect()));
+
+	ui.set_export_format_model(vec_to_model(EXPORT_FORMATS.iter().map(|format| format.to_shared_string()).collect()));
+
+	restore_lc(&ui, &settings.borrow().lc, &input1_type, &input2_type);
+	restore_imp(&ui, &settings.borrow().imp);
+	restore_net(&ui, &settings.borrow().net);
+	restore_coil(&ui, &settings.borrow().coil);
+	restore_core(&ui, &settings.borrow().core, &cores.borrow());
+	restore_cap(&ui, &settings.borrow().cap);
+	ui.set_page(settings.borrow().page);
+
+	set_precision(settings.borrow().precision);
+	set_rounding_mode(&settings.borrow().rounding);
+
+	ui.set_precision_model(vec_to_model(PRECISION_PRESETS.iter().map(|bits| format!("{bits} bits").to_shared_string()).collect()));
+	ui.set_rounding_model(vec_to_model(ROUNDING_MODES.iter().map(|(name, _)| name.to_shared_string()).collect()));
+	ui.set_precision_index(PRECISION_PRESETS.iter().position(|bits| *bits == precision()).map_or(-1, |index| index as i32));
+	ui.set_rounding_index(ROUNDING_MODES.iter().position(|(name, _)| *name == rounding_mode_name()).map_or(0, |index| index as i32));
+	ui.set_precision_digits(precision_digits_text());
+
+	ui.set_e_series_model(vec_to_model([vec!["Off".to_shared_string()], E_SERIES_NAMES.iter().map(|name| name.to_shared_string()).collect()].concat()));
+	ui.set_e_series_index(E_SERIES_NAMES.iter().position(|name| *name == settings.borrow().e_series).map_or(0, |index| index as i32 + 1));
 
-	ui.set_l_model(vec_to_model(henry_units_shared));
-	ui.set_c_model(vec_to_model(farad_units_shared));
-	ui.set_f_model(vec_to_model(hertz_units_shared));
-	ui.set_r_model(vec_to_model(ohm_units_shared));
+	ui.set_music_muted(settings.borrow().music_muted);
+	ui.set_music_started(settings.borrow().music_on_start);
+	ui.set_music_volume(settings.borrow().music_volume);
+	ui.set_music_on_start(settings.borrow().music_on_start);
+
+	music.on_error({
+		let ui_handle = ui.as_weak();
+		move |error| {
+			let _ = ui_handle.upgrade_in_event_loop(move |ui| ui.set_music_error(format!("music could not play: {error}").into()));
+		}
+	});
+	music.set_playing(ui.get_music_started() && !ui.get_music_muted());
+
+	ui.on_state_changed({
+		let ui_handle = ui.as_weak();
+		let settings = settings.clone();
+		let settings_dir = settings_dir.clone();
+		move || save_settings(&ui_handle.unwrap(), &settings, settings_dir.as_deref())
+	});
+
+	ui.on_music_changed({
+		let ui_handle = ui.as_weak();
+		let settings = settings.clone();
+		let settings_dir = settings_dir.clone();
+		move || {
+			let ui = ui_handle.unwrap();
+
+			music.set_volume(ui.get_music_volume());
+			music.set_playing(ui.get_music_started() && !ui.get_music_muted());
+
+			save_settings(&ui, &settings, settings_dir.as_deref());
+		}
+	});
+
+	ui.on_precision_changed({
+		let ui_handle = ui.as_weak();
+		let settings = settings.clone();
+		let settings_dir = settings_dir.clone();
+		move || {
+			let ui = ui_handle.unwrap();
+
+			if let Some(bits) = PRECISION_PRESETS.get(ui.get_precision_index() as usize) {
+				set_precision(*bits);
+			}
+			if let Some((name, _)) = ROUNDING_MODES.get(ui.get_rounding_index() as usize) {
+				set_rounding_mode(name);
+			}
+
+			ui.set_precision_digits(precision_digits_text());
+
+			save_settings(&ui, &settings, settings_dir.as_deref());
+		}
+	});
 
 	ui.on_lc_input1_combo_changed({
 		let ui_handle = ui.as_weak();
@@ -55,180 +253,1550 @@ pub fn start_ui() -> Result<(), Box<dyn Error>> {
 
     ui.on_lc_input2_combo_changed({
         let ui_handle = ui.as_weak();
-		//let type1 = input2_type.clone();
-		//let type2 = input1_type.clone();
+		let type1 = input2_type.clone();
+		let type2 = input1_type.clone();
         move |new_value: SharedString| {
             let ui = ui_handle.unwrap();
-			handle_combobox_changed(new_value, input2_type.clone(), input1_type.clone(), |value| ui.set_lc_input1_combo_text(value));
+			handle_combobox_changed(new_value, type1.clone(), type2.clone(), |value| ui.set_lc_input1_combo_text(value));
         }
     });
 
 	ui.on_lc_calcularot({
 		let ui_handle = ui.as_weak();
-		move |input1_type, input2_type, output_type, input1_text, input2_text| {
+		let lc_target = lc_target.clone();
+		let history = history.clone();
+		let history_model = history_model.clone();
+		let settings = settings.clone();
+		let settings_dir = settings_dir.clone();
+		move |input1_type, input2_type, output_type, input1_text, input2_text, output_format| {
 			let ui = ui_handle.unwrap();
 
-			let input1_group = get_unit_group(&input1_type);
-			let input2_group = get_unit_group(&input2_type);
-			let output_group = get_unit_group(&output_type);
+			let calcularot = || -> Result<SharedString, FieldError> {
+				let input1_group = get_unit_group(&input1_type);
+				let input2_group = get_unit_group(&input2_type);
+				let output_group = get_unit_group(&output_type);
+
+				if input1_group == UnitType::NotSelected {
+					return Err(field_error(LC_INPUT1_FIELD)(CalcError::UnitNotSelected));
+				}
+				if input2_group == UnitType::NotSelected {
+					return Err(field_error(LC_INPUT2_FIELD)(CalcError::UnitNotSelected));
+				}
+				if output_group == UnitType::NotSelected {
+					return Err(field_error(LC_OUTPUT_FIELD)(CalcError::UnitNotSelected));
+				}
+
+				let input1_base = parse_to_base(&input1_text, &input1_group, &input1_type).map_err(field_error(LC_INPUT1_FIELD))?;
+				let input2_base = parse_to_base(&input2_text, &input2_group, &input2_type).map_err(field_error(LC_INPUT2_FIELD))?;
+
+				ui.set_lc_snap_text(SharedString::new());
+				ui.set_lc_pair_lines(ModelRc::default());
+				lc_target.replace(None);
+
+				let result = if input1_group == output_group {
+					input1_base
+				} else if input2_group == output_group {
+					input2_base
+				} else {
+					let result = calculate_lc(input1_base.clone(), input2_base.clone(), input1_group, output_group).map_err(field_error(LC_OUTPUT_FIELD))?;
+
+					if output_group != UnitType::Hertz {
+						lc_target.replace(Some((result.clone(), output_group)));
+					}
 
-			if input1_group == UnitType::NotSelected || input2_group == UnitType::NotSelected || output_group == UnitType::NotSelected {
-				return
+					//INFO: a computed L or C, the inputs are the other part and the frequency in some order
+					if let (Some(series), true) = (chosen_series(&ui), output_group != UnitType::Hertz) {
+						let (other, f0) = match input1_group {
+							UnitType::Hertz => (input2_base, input1_base),
+							_ => (input1_base, input2_base),
+						};
+
+						ui.set_lc_snap_text(snap_text(series, &result, output_group, &other, &f0).map_err(field_error(LC_OUTPUT_FIELD))?);
+					}
+
+					result
+				};
+
+				let style: NumberStyle = output_format.parse().map_err(field_error(LC_OUTPUT_FIELD))?;
+
+				output_text(result, &output_group, &output_type, |value| value.to_styled_string(style)).map_err(field_error(LC_OUTPUT_FIELD))
+			};
+
+			match calcularot() {
+				Ok(result) => {
+					ui.set_lc_error_field(NO_FIELD);
+					ui.set_lc_result_text(result.clone());
+
+					push_history(&history, &history_model, HistoryEntry::Lc(LcRecord {
+						input1_text: input1_text.to_string(),
+						input1_unit: input1_type.to_string(),
+						input2_text: input2_text.to_string(),
+						input2_unit: input2_type.to_string(),
+						output_unit: output_type.to_string(),
+						result: result.to_string(),
+					}));
+
+					save_settings(&ui, &settings, settings_dir.as_deref());
+				}
+				Err((field, message)) => {
+					ui.set_lc_error_field(field);
+					ui.set_lc_error_text(message);
+				}
 			}
+		}
+	});
+
+	ui.on_lc_find_pairs({
+		let ui_handle = ui.as_weak();
+		let lc_target = lc_target.clone();
+		move |series, tolerance| {
+			let ui = ui_handle.unwrap();
+
+			let find = || -> Result<Vec<SharedString>, FieldError> {
+				let Some((target, unit_type)) = lc_target.borrow().clone() else {
+					return Err((LC_PAIRS_FIELD, "calcularot an L or C first".to_shared_string()));
+				};
+
+				let series: ESeries = series.parse().map_err(field_error(LC_PAIRS_FIELD))?;
 
-			let input1_bigfloat: BFloat = BFloat::from_str(&input1_text).unwrap();
-			let input2_bigfloat: BFloat = BFloat::from_str(&input2_text).unwrap();
+				//INFO: the tolerance is typed in %, empty keeps every pair
+				let tolerance = match tolerance.trim() {
+					"" => None,
+					text => Some(text.parse::<BFloat>().map_err(field_error(LC_PAIRS_FIELD))? / BFloat::from(100)),
+				};
 
-			if input1_group == output_group {
-				ui.set_lc_result_text(
-					convert_measure(input1_bigfloat, &input1_group, &input1_type, &output_type).to_shared_string()
-				);
-				return
-			} else if input2_group == output_group {
-			    ui.set_lc_result_text(
-					convert_measure(input2_bigfloat, &input2_group, &input2_type, &output_type).to_shared_string()
-				);
-				return
+				let pairs = best_pairs(&target, unit_type, series, tolerance.as_ref(), PAIR_LIMIT).map_err(field_error(LC_PAIRS_FIELD))?;
+
+				if pairs.is_empty() {
+					return Err((LC_PAIRS_FIELD, "no pair within the tolerance".to_shared_string()));
+				}
+
+				let part = |value: BFloat| output_text(value, &unit_type, AUTO_UNIT, |value| value.to_styled_string(NumberStyle::Significant(3))).map_err(field_error(LC_PAIRS_FIELD));
+
+				pairs
+					.into_iter()
+					.map(|pair| {
+						let error = pair.error * BFloat::from(100);
+						let sign = if error.0.is_negative() { "" } else { "+" };
+
+						Ok(format!(
+							"{} + {} in {} = {} ({sign}{} %)",
+							part(pair.first)?,
+							part(pair.second)?,
+							pair.connection,
+							part(pair.value)?,
+							error.to_styled_string(NumberStyle::Significant(2)),
+						).into())
+					})
+					.collect()
+			};
+
+			match find() {
+				Ok(lines) => {
+					ui.set_lc_error_field(NO_FIELD);
+					ui.set_lc_pair_lines(vec_to_model(lines));
+				}
+				Err((field, message)) => {
+					ui.set_lc_error_field(field);
+					ui.set_lc_error_text(message);
+				}
 			}
+		}
+	});
+
+	ui.on_lc_monte_carlo({
+		let ui_handle = ui.as_weak();
+		move |tolerance1, tolerance2, _, samples, distribution| {
+			let ui = ui_handle.unwrap();
 
-			let input1_base = convert_to_base(input1_bigfloat, &input1_group, &input1_type);
-			let input2_base = convert_to_base(input2_bigfloat, &input2_group, &input2_type);
+			let prepare = || -> Result<([Toleranced; 2], [UnitType; 3], usize, Distribution), FieldError> {
+				let (parts, groups) = lc_tolerance_parts(&ui, &tolerance1, &tolerance2)?;
+				let (samples, distribution) = run_settings(&samples, &distribution).map_err(field_error(LC_TOLERANCE_FIELD))?;
+				Ok((parts, groups, samples, distribution))
+			};
+
+			let (parts, groups, samples, distribution) = match prepare() {
+				Ok(prepared) => prepared,
+				Err((field, message)) => {
+					ui.set_lc_error_field(field);
+					ui.set_lc_error_text(message);
+					return;
+				}
+			};
 
-			let result = calculate_lc(input1_base, input2_base, input1_group, output_group);
-			ui.set_lc_result_text(result.to_shared_string());
+			ui.set_lc_error_field(NO_FIELD);
+			ui.set_lc_tolerance_running(true);
+
+			let evaluate = move |values: &[BFloat]| lc_evaluate(values, &groups);
+
+			spawn_monte_carlo(ui_handle.clone(), parts.to_vec(), distribution, samples, evaluate, move |ui, statistics| {
+				ui.set_lc_tolerance_running(false);
+
+				match statistics.and_then(|statistics| spread_lines(&[(quantity_name(groups[2]), groups[2])], &statistics)) {
+					Ok((lines, histograms)) => {
+						ui.set_lc_tolerance_lines(vec_to_model(lines));
+						ui.set_lc_tolerance_histograms(ModelRc::new(VecModel::from(histograms)));
+					}
+					Err(error) => {
+						ui.set_lc_error_field(LC_TOLERANCE_FIELD);
+						ui.set_lc_error_text(error.to_shared_string());
+					}
+				}
+			});
+		}
+	});
+
+	ui.on_lc_worst_case({
+		let ui_handle = ui.as_weak();
+		move |tolerance1, tolerance2, _| {
+			let ui = ui_handle.unwrap();
+
+			let run = || -> Result<Vec<SharedString>, FieldError> {
+				let (parts, groups) = lc_tolerance_parts(&ui, &tolerance1, &tolerance2)?;
+				let nominals = parts.iter().map(|part| part.nominal.clone()).collect::<Vec<BFloat>>();
+
+				let sensitivity = sensitivities(&nominals, |values| lc_evaluate(values, &groups)).map_err(field_error(LC_TOLERANCE_FIELD))?;
+				let extremes = worst_case(&parts, |values| lc_evaluate(values, &groups)).map_err(field_error(LC_TOLERANCE_FIELD))?;
+
+				let part_names = [quantity_name(groups[0]), quantity_name(groups[1])];
+
+				worst_case_lines(&part_names, &[(quantity_name(groups[2]), groups[2])], &sensitivity, &extremes).map_err(field_error(LC_TOLERANCE_FIELD))
+			};
+
+			match run() {
+				Ok(lines) => {
+					ui.set_lc_error_field(NO_FIELD);
+					ui.set_lc_tolerance_lines(vec_to_model(lines));
+					ui.set_lc_tolerance_histograms(ModelRc::default());
+				}
+				Err((field, message)) => {
+					ui.set_lc_error_field(field);
+					ui.set_lc_error_text(message);
+				}
+			}
 		}
 	});
 
 	ui.on_imp_calcularot({
 		let ui_handle = ui.as_weak();
-		move |l_str, c_str, f_str, l_type, c_type, f_type, type_index, imp_type, xl_type, xc_type, rf_type| {
+		let history = history.clone();
+		let history_model = history_model.clone();
+		let settings = settings.clone();
+		let settings_dir = settings_dir.clone();
+		move |l_str, c_str, f_str, r_str, l_type, c_type, f_type, r_type, type_index, imp_type, zr_type, zx_type, phase_type, y_type, xl_type, xc_type, rf_type, z0_type, bw_type, fl_type, fh_type, formats| {
+			let ui = ui_handle.unwrap();
+
+			let calcularot = || -> Result<(), FieldError> {
+				if type_index == -1 {
+					return Err((IMP_GENERAL_FIELD, "select series or parallel".to_shared_string()));
+				}
+
+				let (l, c, f) = get_full_value_list(&l_str, &c_str, &f_str, &l_type, &c_type, &f_type, &ui)?;
+
+				let r = optional_input(&r_str, &UnitType::Ohm, &r_type).map_err(field_error(IMP_R_FIELD))?;
+
+				//INFO: an empty L or C field was derived from the other two, that is the part to buy
+				let snap = match (chosen_series(&ui), l_str.trim().is_empty(), c_str.trim().is_empty()) {
+					(Some(series), true, _) => snap_text(series, &l, UnitType::Henry, &c, &f),
+					(Some(series), _, true) => snap_text(series, &c, UnitType::Farad, &l, &f),
+					_ => Ok(SharedString::new()),
+				};
+				ui.set_imp_snap_text(snap.map_err(field_error(IMP_GENERAL_FIELD))?);
+
+				let omega = get_omega(f);
+
+				let (result, resonance) = match type_index {
+					0 => (
+						calculate_impedance_series(r.clone(), l.clone(), c.clone(), omega),
+						calculate_resonance_series(r, l, c),
+					),
+					1 => (
+						calculate_impedance_parallel(r.clone(), l.clone(), c.clone(), omega),
+						calculate_resonance_parallel(r, l, c),
+					),
+					_ => unreachable!()
+				};
+
+				let phase = match phase_type.as_str() {
+					"rad" => result.phase(),
+					_ => result.phase().to_degrees(),
+				};
+
+				//INFO: formats come in the order the outputs are shown, |Z| first and f2 last
+				let style = |index: usize| format_style(&formats, index).map_err(field_error(IMP_GENERAL_FIELD));
+
+				let output = |value: BFloat, unit_type: UnitType, target_unit: &SharedString, index: usize| {
+					let style = style(index)?;
+					output_text(value, &unit_type, target_unit, |value| value.to_styled_string(style)).map_err(field_error(IMP_GENERAL_FIELD))
+				};
+
+				ui.set_impedance(output(result.magnitude(), UnitType::Ohm, &imp_type, 0)?);
+				ui.set_impedance_real(output(result.impedance.re.clone(), UnitType::Ohm, &zr_type, 1)?);
+				ui.set_impedance_imag(output(result.impedance.im.clone(), UnitType::Ohm, &zx_type, 2)?);
+				ui.set_phase(phase.to_styled_string(style(3)?).into());
+				ui.set_admittance(output(result.admittance_magnitude(), UnitType::Siemens, &y_type, 4)?);
+				ui.set_inductive_reactance(output(result.inductive_reactance, UnitType::Ohm, &xl_type, 5)?);
+				ui.set_capacitive_reactance(output(result.capacitive_reactance, UnitType::Ohm, &xc_type, 6)?);
+				ui.set_resonant_frequency(output(resonance.resonant_frequency, UnitType::Hertz, &rf_type, 7)?);
+				ui.set_characteristic_impedance(output(resonance.characteristic_impedance, UnitType::Ohm, &z0_type, 8)?);
+				ui.set_quality_factor(resonance.quality_factor.to_styled_string(style(9)?).into());
+				ui.set_damping_ratio(resonance.damping_ratio.to_styled_string(style(10)?).into());
+				ui.set_bandwidth(output(resonance.bandwidth, UnitType::Hertz, &bw_type, 11)?);
+				ui.set_lower_frequency(output(resonance.lower_frequency, UnitType::Hertz, &fl_type, 12)?);
+				ui.set_upper_frequency(output(resonance.upper_frequency, UnitType::Hertz, &fh_type, 13)?);
+
+				Ok(())
+			};
+
+			match calcularot() {
+				Ok(()) => {
+					ui.set_imp_error_field(NO_FIELD);
+
+					let outputs = [
+						("|Z|", ui.get_impedance(), &imp_type),
+						("f0", ui.get_resonant_frequency(), &rf_type),
+						("R", ui.get_impedance_real(), &zr_type),
+						("X", ui.get_impedance_imag(), &zx_type),
+						("φ", ui.get_phase(), &phase_type),
+						("|Y|", ui.get_admittance(), &y_type),
+						("X_L", ui.get_inductive_reactance(), &xl_type),
+						("X_C", ui.get_capacitive_reactance(), &xc_type),
+						("Z0", ui.get_characteristic_impedance(), &z0_type),
+						("Q", ui.get_quality_factor(), &SharedString::new()),
+						("ζ", ui.get_damping_ratio(), &SharedString::new()),
+						("BW", ui.get_bandwidth(), &bw_type),
+						("f1", ui.get_lower_frequency(), &fl_type),
+						("f2", ui.get_upper_frequency(), &fh_type),
+					];
+
+					push_history(&history, &history_model, HistoryEntry::Imp(ImpRecord {
+						inductance: l_str.to_string(),
+						capacitance: c_str.to_string(),
+						frequency: f_str.to_string(),
+						resistance: r_str.to_string(),
+						l_unit: l_type.to_string(),
+						c_unit: c_type.to_string(),
+						f_unit: f_type.to_string(),
+						r_unit: r_type.to_string(),
+						circuit: type_index,
+						outputs: outputs
+							.into_iter()
+							.map(|(name, value, unit)| (name.to_string(), value.to_string(), unit.to_string()))
+							.collect(),
+					}));
+
+					save_settings(&ui, &settings, settings_dir.as_deref());
+				}
+				Err((field, message)) => {
+					ui.set_imp_error_field(field);
+					ui.set_imp_error_text(message);
+				}
+			}
+		}
+	});
+
+	ui.on_imp_sweep({
+		let ui_handle = ui.as_weak();
+		move |l_str, c_str, r_str, l_type, c_type, r_type, type_index, start_str, start_type, stop_str, stop_type, points_str, scale_str, format| {
+			let ui = ui_handle.unwrap();
+
+			let calcularot = || -> Result<(), FieldError> {
+				if type_index == -1 {
+					return Err((IMP_GENERAL_FIELD, "select series or parallel".to_shared_string()));
+				}
+
+				//INFO: unlike a single point, a sweep cannot derive L or C, it has no one frequency to use
+				let l = parse_to_base(&l_str, &UnitType::Henry, &l_type).map_err(field_error(IMP_L_FIELD))?;
+				let c = parse_to_base(&c_str, &UnitType::Farad, &c_type).map_err(field_error(IMP_C_FIELD))?;
+				let r = optional_input(&r_str, &UnitType::Ohm, &r_type).map_err(field_error(IMP_R_FIELD))?;
+
+				let start = parse_to_base(&start_str, &UnitType::Hertz, &start_type).map_err(field_error(IMP_SWEEP_START_FIELD))?;
+				let stop = parse_to_base(&stop_str, &UnitType::Hertz, &stop_type).map_err(field_error(IMP_SWEEP_STOP_FIELD))?;
+
+				let points: usize = points_str
+					.trim()
+					.parse()
+					.map_err(|_| (IMP_SWEEP_FIELD, format!("'{points_str}' is not a number of points").to_shared_string()))?;
+				let scale: SweepScale = scale_str.parse().map_err(field_error(IMP_SWEEP_FIELD))?;
+				let style: NumberStyle = format.parse().map_err(field_error(IMP_SWEEP_FIELD))?;
+
+				let frequencies = sweep_frequencies(start, stop, points, scale).map_err(field_error(IMP_SWEEP_FIELD))?;
+
+				let cell = |value: BFloat, unit_type: UnitType| -> Result<StandardListViewItem, FieldError> {
+					let text = output_text(value, &unit_type, AUTO_UNIT, |value| value.to_styled_string(style)).map_err(field_error(IMP_SWEEP_FIELD))?;
+					Ok(StandardListViewItem::from(text.as_str()))
+				};
+
+				let rows = sweep_rlc(r.clone(), l.clone(), c.clone(), type_index == 1, &frequencies)
+					.into_iter()
+					.map(|point| {
+						let row = vec![
+							cell(point.frequency, UnitType::Hertz)?,
+							cell(point.impedance, UnitType::Ohm)?,
+							StandardListViewItem::from(point.phase.to_degrees().to_styled_string(style).as_str()),
+							cell(point.inductive_reactance, UnitType::Ohm)?,
+							cell(point.capacitive_reactance, UnitType::Ohm)?,
+						];
+						Ok(ModelRc::new(VecModel::from(row)))
+					})
+					.collect::<Result<Vec<_>, FieldError>>()?;
+
+				//INFO: the plot always runs low to high on a dense log scale, whatever the table uses
+				let (low, high) = match frequencies.first() <= frequencies.last() {
+					true => (frequencies[0].clone(), frequencies[frequencies.len() - 1].clone()),
+					false => (frequencies[frequencies.len() - 1].clone(), frequencies[0].clone()),
+				};
+				let plot_frequencies = sweep_frequencies(low, high, PLOT_POINTS, SweepScale::Logarithmic).map_err(field_error(IMP_SWEEP_FIELD))?;
+
+				let f0 = calculate_resonant_frequency(l.clone(), c.clone());
+				let plot = render_bode(&sweep_rlc(r, l, c, type_index == 1, &plot_frequencies), &f0);
+
+				let decade = |exponent: i32| output_text(pow10(exponent), &UnitType::Ohm, AUTO_UNIT, |value| value.to_styled_string(style)).map_err(field_error(IMP_SWEEP_FIELD));
+				let f0_text = output_text(f0, &UnitType::Hertz, AUTO_UNIT, |value| value.to_styled_string(style)).map_err(field_error(IMP_SWEEP_FIELD))?;
+
+				ui.set_sweep_rows(ModelRc::new(VecModel::from(rows)));
+				ui.set_sweep_plot(plot.image);
+				ui.set_sweep_plot_caption(format!(
+					"|Z| {} to {} on log axes, phase -90° to 90°, f0 {f0_text} in red",
+					decade(plot.magnitude_decades.0)?,
+					decade(plot.magnitude_decades.1)?,
+				).into());
+
+				Ok(())
+			};
+
+			match calcularot() {
+				Ok(()) => {
+					ui.set_imp_error_field(NO_FIELD);
+				}
+				Err((field, message)) => {
+					ui.set_imp_error_field(field);
+					ui.set_imp_error_text(message);
+				}
+			}
+		}
+	});
+
+	ui.on_imp_monte_carlo({
+		let ui_handle = ui.as_weak();
+		move |tolerance_l, tolerance_c, tolerance_r, samples, distribution| {
 			let ui = ui_handle.unwrap();
 
-			if type_index == -1 {
+			let prepare = || -> Result<(Vec<Toleranced>, i32, BFloat, usize, Distribution), FieldError> {
+				let (parts, type_index, omega) = imp_tolerance_parts(&ui, &tolerance_l, &tolerance_c, &tolerance_r)?;
+				let (samples, distribution) = run_settings(&samples, &distribution).map_err(field_error(IMP_TOLERANCE_FIELD))?;
+				Ok((parts, type_index, omega, samples, distribution))
+			};
+
+			let (parts, type_index, omega, samples, distribution) = match prepare() {
+				Ok(prepared) => prepared,
+				Err((field, message)) => {
+					ui.set_imp_error_field(field);
+					ui.set_imp_error_text(message);
+					return;
+				}
+			};
+
+			ui.set_imp_error_field(NO_FIELD);
+			ui.set_imp_tolerance_running(true);
+
+			let evaluate = move |values: &[BFloat]| Ok(imp_evaluate(values, type_index, &omega));
+
+			spawn_monte_carlo(ui_handle.clone(), parts, distribution, samples, evaluate, |ui, statistics| {
+				ui.set_imp_tolerance_running(false);
+
+				match statistics.and_then(|statistics| spread_lines(&IMP_TOLERANCE_RESULTS, &statistics)) {
+					Ok((lines, histograms)) => {
+						ui.set_imp_tolerance_lines(vec_to_model(lines));
+						ui.set_imp_tolerance_histograms(ModelRc::new(VecModel::from(histograms)));
+					}
+					Err(error) => {
+						ui.set_imp_error_field(IMP_TOLERANCE_FIELD);
+						ui.set_imp_error_text(error.to_shared_string());
+					}
+				}
+			});
+		}
+	});
+
+	ui.on_imp_worst_case({
+		let ui_handle = ui.as_weak();
+		move |tolerance_l, tolerance_c, tolerance_r| {
+			let ui = ui_handle.unwrap();
+
+			let run = || -> Result<Vec<SharedString>, FieldError> {
+				let (parts, type_index, omega) = imp_tolerance_parts(&ui, &tolerance_l, &tolerance_c, &tolerance_r)?;
+				let nominals = parts.iter().map(|part| part.nominal.clone()).collect::<Vec<BFloat>>();
+
+				let sensitivity = sensitivities(&nominals, |values| Ok(imp_evaluate(values, type_index, &omega))).map_err(field_error(IMP_TOLERANCE_FIELD))?;
+				let extremes = worst_case(&parts, |values| Ok(imp_evaluate(values, type_index, &omega))).map_err(field_error(IMP_TOLERANCE_FIELD))?;
+
+				worst_case_lines(&["L", "C", "R"][..parts.len()], &IMP_TOLERANCE_RESULTS, &sensitivity, &extremes).map_err(field_error(IMP_TOLERANCE_FIELD))
+			};
+
+			match run() {
+				Ok(lines) => {
+					ui.set_imp_error_field(NO_FIELD);
+					ui.set_imp_tolerance_lines(vec_to_model(lines));
+					ui.set_imp_tolerance_histograms(ModelRc::default());
+				}
+				Err((field, message)) => {
+					ui.set_imp_error_field(field);
+					ui.set_imp_error_text(message);
+				}
+			}
+		}
+	});
+
+	ui.on_net_calcularot({
+		let ui_handle = ui.as_weak();
+		let history = history.clone();
+		let history_model = history_model.clone();
+		let settings = settings.clone();
+		let settings_dir = settings_dir.clone();
+		move |network_text, f_str, f_type, imp_type, zr_type, zx_type, phase_type, y_type, formats| {
+			let ui = ui_handle.unwrap();
+
+			let calcularot = || -> Result<(), FieldError> {
+				let network: Network = network_text.parse().map_err(field_error(NET_NETWORK_FIELD))?;
+				let f = parse_to_base(&f_str, &UnitType::Hertz, &f_type).map_err(field_error(NET_F_FIELD))?;
+
+				let omega = get_omega(f);
+				let impedance = network.impedance(&omega);
+				let admittance = network.admittance(&omega);
+
+				let phase = match phase_type.as_str() {
+					"rad" => impedance.argument(),
+					_ => impedance.argument().to_degrees(),
+				};
+
+				//INFO: formats come in the order the outputs are shown, |Z| first and |Y| last
+				let style = |index: usize| format_style(&formats, index).map_err(field_error(NET_GENERAL_FIELD));
+
+				let output = |value: BFloat, unit_type: UnitType, target_unit: &SharedString, index: usize| {
+					let style = style(index)?;
+					output_text(value, &unit_type, target_unit, |value| value.to_styled_string(style)).map_err(field_error(NET_GENERAL_FIELD))
+				};
+
+				ui.set_network_summary(format!("{} parts: {network}", network.element_count()).into());
+				ui.set_net_impedance(output(impedance.magnitude(), UnitType::Ohm, &imp_type, 0)?);
+				ui.set_net_impedance_real(output(impedance.re.clone(), UnitType::Ohm, &zr_type, 1)?);
+				ui.set_net_impedance_imag(output(impedance.im.clone(), UnitType::Ohm, &zx_type, 2)?);
+				ui.set_net_phase(phase.to_styled_string(style(3)?).into());
+				ui.set_net_admittance(output(admittance.magnitude(), UnitType::Siemens, &y_type, 4)?);
+
+				Ok(())
+			};
+
+			match calcularot() {
+				Ok(()) => {
+					ui.set_net_error_field(NO_FIELD);
+
+					let outputs = [
+						("|Z|", ui.get_net_impedance(), &imp_type),
+						("R", ui.get_net_impedance_real(), &zr_type),
+						("X", ui.get_net_impedance_imag(), &zx_type),
+						("φ", ui.get_net_phase(), &phase_type),
+						("|Y|", ui.get_net_admittance(), &y_type),
+					];
+
+					push_history(&history, &history_model, HistoryEntry::Net(NetRecord {
+						network: network_text.to_string(),
+						frequency: f_str.to_string(),
+						f_unit: f_type.to_string(),
+						outputs: outputs
+							.into_iter()
+							.map(|(name, value, unit)| (name.to_string(), value.to_string(), unit.to_string()))
+							.collect(),
+					}));
+
+					save_settings(&ui, &settings, settings_dir.as_deref());
+				}
+				Err((field, message)) => {
+					ui.set_net_error_field(field);
+					ui.set_net_error_text(message);
+				}
+			}
+		}
+	});
+
+	ui.on_coil_calcularot({
+		let ui_handle = ui.as_weak();
+		let settings = settings.clone();
+		let settings_dir = settings_dir.clone();
+		move |shape, turns, diameter, diameter_type, length, length_type, wire, wire_type, l_type, length_out_type, outer_type, wire_length_type, format| {
+			let ui = ui_handle.unwrap();
+
+			let calcularot = || -> Result<(), FieldError> {
+				let geometry = coil_geometry(&shape, &diameter, &diameter_type, &length, &length_type, &wire, &wire_type)?;
+				let turns: BFloat = turns.parse().map_err(field_error(COIL_TURNS_FIELD))?;
+
+				let coil = geometry.coil(&turns).map_err(field_error(COIL_GENERAL_FIELD))?;
+
+				let style: NumberStyle = format.parse().map_err(field_error(COIL_GENERAL_FIELD))?;
+				let output = |value: BFloat, unit_type: UnitType, target_unit: &SharedString| {
+					output_text(value, &unit_type, target_unit, |value| value.to_styled_string(style)).map_err(field_error(COIL_GENERAL_FIELD))
+				};
+
+				ui.set_coil_inductance(output(coil.inductance, UnitType::Henry, &l_type)?);
+				ui.set_coil_winding_length(output(coil.length, UnitType::Meter, &length_out_type)?);
+				ui.set_coil_outer_diameter(output(coil.outer_diameter, UnitType::Meter, &outer_type)?);
+				ui.set_coil_wire_length(output(coil.wire_length, UnitType::Meter, &wire_length_type)?);
+
+				Ok(())
+			};
+
+			match calcularot() {
+				Ok(()) => {
+					ui.set_coil_error_field(NO_FIELD);
+					save_settings(&ui, &settings, settings_dir.as_deref());
+				}
+				Err((field, message)) => {
+					ui.set_coil_error_field(field);
+					ui.set_coil_error_text(message);
+				}
+			}
+		}
+	});
+
+	ui.on_coil_solve({
+		let ui_handle = ui.as_weak();
+		move |shape, target, target_type, diameter, diameter_type, length, length_type, wire, wire_type| {
+			let ui = ui_handle.unwrap();
+
+			let solve = || -> Result<(), FieldError> {
+				let geometry = coil_geometry(&shape, &diameter, &diameter_type, &length, &length_type, &wire, &wire_type)?;
+				let target = parse_to_base(&target, &UnitType::Henry, &target_type).map_err(field_error(COIL_TARGET_FIELD))?;
+
+				let turns = geometry.turns_for(&target).map_err(field_error(COIL_TARGET_FIELD))?;
+
+				let whole = whole_turns(&turns);
+				let coil = geometry.coil(&BFloat::from(whole)).map_err(field_error(COIL_TARGET_FIELD))?;
+
+				let inductance = output_text(coil.inductance, &UnitType::Henry, AUTO_UNIT, |value| value.to_styled_string(NumberStyle::Significant(4))).map_err(field_error(COIL_TARGET_FIELD))?;
+
+				ui.set_coil_solve_text(format!(
+					"{} turns exactly, {whole} wound give {inductance}",
+					turns.to_styled_string(NumberStyle::Decimals(2)),
+				).into());
+				ui.set_coil_turns(whole.to_shared_string());
+
+				Ok(())
+			};
+
+			match solve() {
+				Ok(()) => {
+					ui.set_coil_error_field(NO_FIELD);
+					ui.invoke_run_coil();
+				}
+				Err((field, message)) => {
+					ui.set_coil_error_field(field);
+					ui.set_coil_error_text(message);
+				}
+			}
+		}
+	});
+
+	ui.on_core_select({
+		let ui_handle = ui.as_weak();
+		let cores = cores.clone();
+		move |name| {
+			let ui = ui_handle.unwrap();
+
+			let Some(core) = cores.borrow().iter().find(|core| core.name == name.as_str()).cloned() else {
 				return;
+			};
+
+			let select = || -> Result<(), CalcError> {
+				ui.set_core_al(output_text(core.al.clone(), &UnitType::Henry, "nH", |value| value.to_styled_string(NumberStyle::Significant(4)))?);
+				ui.set_core_al_unit("nH".into());
+				ui.set_core_info(core_info(&core)?);
+
+				Ok(())
+			};
+
+			match select() {
+				Ok(()) => ui.set_core_error_field(NO_FIELD),
+				Err(error) => {
+					ui.set_core_error_field(CORE_GENERAL_FIELD);
+					ui.set_core_error_text(error.to_shared_string());
+				}
+			}
+		}
+	});
+
+	ui.on_core_calcularot({
+		let ui_handle = ui.as_weak();
+		let core_inductance = core_inductance.clone();
+		let settings = settings.clone();
+		let settings_dir = settings_dir.clone();
+		move |al, al_type, turns, l_type, format| {
+			let ui = ui_handle.unwrap();
+
+			let calcularot = || -> Result<SharedString, FieldError> {
+				let al = parse_to_base(&al, &UnitType::Henry, &al_type).map_err(field_error(CORE_AL_FIELD))?;
+				let turns: BFloat = turns.parse().map_err(field_error(CORE_TURNS_FIELD))?;
+
+				let inductance = al_inductance(&al, &turns).map_err(field_error(CORE_TURNS_FIELD))?;
+				core_inductance.replace(Some(inductance.clone()));
+
+				let style: NumberStyle = format.parse().map_err(field_error(CORE_GENERAL_FIELD))?;
+
+				output_text(inductance, &UnitType::Henry, &l_type, |value| value.to_styled_string(style)).map_err(field_error(CORE_GENERAL_FIELD))
+			};
+
+			match calcularot() {
+				Ok(inductance) => {
+					ui.set_core_error_field(NO_FIELD);
+					ui.set_core_inductance(inductance);
+					save_settings(&ui, &settings, settings_dir.as_deref());
+				}
+				Err((field, message)) => {
+					core_inductance.replace(None);
+					ui.set_core_error_field(field);
+					ui.set_core_error_text(message);
+				}
 			}
-			
-			let values_option = get_full_value_list(&l_str, &c_str, &f_str, &l_type, &c_type, &f_type, &ui);
+		}
+	});
+
+	ui.on_core_solve({
+		let ui_handle = ui.as_weak();
+		move |al, al_type, target, target_type| {
+			let ui = ui_handle.unwrap();
+
+			let solve = || -> Result<(), FieldError> {
+				let al = parse_to_base(&al, &UnitType::Henry, &al_type).map_err(field_error(CORE_AL_FIELD))?;
+				let target = parse_to_base(&target, &UnitType::Henry, &target_type).map_err(field_error(CORE_TARGET_FIELD))?;
+
+				let turns = al_turns(&al, &target).map_err(field_error(CORE_TARGET_FIELD))?;
+
+				let whole = whole_turns(&turns);
+				let wound = al_inductance(&al, &BFloat::from(whole)).map_err(field_error(CORE_TARGET_FIELD))?;
 
-			if values_option.is_none() {
+				let inductance = output_text(wound, &UnitType::Henry, AUTO_UNIT, |value| value.to_styled_string(NumberStyle::Significant(4))).map_err(field_error(CORE_TARGET_FIELD))?;
+
+				ui.set_core_solve_text(format!(
+					"{} turns exactly, {whole} wound give {inductance}",
+					turns.to_styled_string(NumberStyle::Decimals(2)),
+				).into());
+				ui.set_core_turns(whole.to_shared_string());
+
+				Ok(())
+			};
+
+			match solve() {
+				Ok(()) => {
+					ui.set_core_error_field(NO_FIELD);
+					ui.invoke_run_core();
+				}
+				Err((field, message)) => {
+					ui.set_core_error_field(field);
+					ui.set_core_error_text(message);
+				}
+			}
+		}
+	});
+
+	//INFO: the inductance goes over to 10 significant digits in the picked prefix, not as the rounded text shown;
+	//INFO: every digit of the working precision would fill the field with the noise of the binary value
+	ui.on_core_send({
+		let ui_handle = ui.as_weak();
+		let core_inductance = core_inductance.clone();
+		let type1 = input1_type.clone();
+		let type2 = input2_type.clone();
+		move |page| {
+			let ui = ui_handle.unwrap();
+
+			let Some(inductance) = core_inductance.borrow().clone() else {
+				ui.set_core_error_field(CORE_GENERAL_FIELD);
+				ui.set_core_error_text("nothing to send yet, calcularot first".into());
 				return;
+			};
+
+			let unit = ui.get_core_l_unit();
+			let text = match output_text(inductance, &UnitType::Henry, &unit, |value| value.to_styled_string(NumberStyle::default())) {
+				Ok(text) => text,
+				Err(error) => {
+					ui.set_core_error_field(CORE_GENERAL_FIELD);
+					ui.set_core_error_text(error.to_shared_string());
+					return;
+				}
+			};
+
+			match page {
+				LC_PAGE => {
+					//INFO: the input that already holds an L, else a free one, else the second, the first keeps its C or f
+					let first = *type1.borrow() == UnitType::Henry
+						|| (*type2.borrow() != UnitType::Henry && *type1.borrow() == UnitType::NotSelected);
+
+					match first {
+						true => {
+							type1.replace(UnitType::Henry);
+							ui.set_lc_input1_combo_text(unit);
+							ui.set_lc_input1_text(text);
+						}
+						false => {
+							type2.replace(UnitType::Henry);
+							ui.set_lc_input2_combo_text(unit);
+							ui.set_lc_input2_text(text);
+						}
+					}
+				}
+				IMP_PAGE => {
+					ui.set_l_unit(unit);
+					ui.set_inductance(text);
+				}
+				_ => return,
+			}
+
+			ui.set_core_error_field(NO_FIELD);
+			ui.set_page(page);
+		}
+	});
+
+	ui.on_core_reload({
+		let ui_handle = ui.as_weak();
+		let cores = cores.clone();
+		let settings_dir = settings_dir.clone();
+		move || {
+			let ui = ui_handle.unwrap();
+
+			let (loaded, note) = load_catalog(settings_dir.as_deref());
+
+			ui.set_core_model(vec_to_model(loaded.iter().map(|core| core.name.to_shared_string()).collect()));
+			ui.set_core_catalog_text(note.into());
+			ui.set_core_info(loaded.iter().find(|core| core.name == ui.get_core_name().as_str()).and_then(|core| core_info(core).ok()).unwrap_or_default());
+
+			cores.replace(loaded);
+		}
+	});
+
+	ui.on_core_read_catalog({
+		let settings_dir = settings_dir.clone();
+		move || read_catalog(settings_dir.as_deref()).into()
+	});
+
+	ui.on_core_save_catalog({
+		let ui_handle = ui.as_weak();
+		let settings_dir = settings_dir.clone();
+		move |text| {
+			let ui = ui_handle.unwrap();
+
+			match save_catalog(settings_dir.as_deref(), &text) {
+				Ok(()) => {
+					ui.invoke_core_reload();
+					true
+				}
+				Err(error) => {
+					ui.set_core_catalog_text(format!("not saved, {error}").into());
+					false
+				}
 			}
+		}
+	});
+
+	ui.on_cap_select_dielectric({
+		let ui_handle = ui.as_weak();
+		move |name| {
+			let ui = ui_handle.unwrap();
+
+			//INFO: the listed text as it is, Custom keeps whatever εr is typed in
+			if let Some((_, permittivity)) = DIELECTRICS.iter().find(|(listed, _)| *listed == name.as_str()) {
+				ui.set_cap_permittivity(permittivity.to_shared_string());
+			}
+		}
+	});
+
+	ui.on_cap_calcularot({
+		let ui_handle = ui.as_weak();
+		let settings = settings.clone();
+		let settings_dir = settings_dir.clone();
+		move |shape, permittivity, area, area_type, separation, separation_type, inner, inner_type, outer, outer_type, length, length_type, c_type, z_type, format| {
+			let ui = ui_handle.unwrap();
+
+			let calcularot = || -> Result<(), FieldError> {
+				let shape: CapacitorShape = shape.parse().map_err(field_error(CAP_GENERAL_FIELD))?;
+				let permittivity = permittivity_input(&permittivity)?;
+				let general = field_error(CAP_GENERAL_FIELD);
+
+				let (capacitance, impedance) = match shape {
+					CapacitorShape::ParallelPlate => {
+						let area = parse_to_base(&area, &UnitType::SquareMeter, &area_type).map_err(field_error(CAP_AREA_FIELD))?;
+						let separation = parse_to_base(&separation, &UnitType::Meter, &separation_type).map_err(field_error(CAP_SEPARATION_FIELD))?;
 
-			let (l, c, f) = values_option.unwrap();
+						(plate_capacitance(&area, &separation, &permittivity).map_err(&general)?, None)
+					}
+					CapacitorShape::Coaxial => {
+						let inner = parse_to_base(&inner, &UnitType::Meter, &inner_type).map_err(field_error(CAP_INNER_FIELD))?;
+						let outer = parse_to_base(&outer, &UnitType::Meter, &outer_type).map_err(field_error(CAP_OUTER_FIELD))?;
+						let length = parse_to_base(&length, &UnitType::Meter, &length_type).map_err(field_error(CAP_LENGTH_FIELD))?;
 
-			let omega = get_omega(f);
+						(
+							coax_capacitance(&inner, &outer, &length, &permittivity).map_err(&general)?,
+							Some(coax_impedance(&inner, &outer, &permittivity).map_err(&general)?),
+						)
+					}
+				};
 
-			let (impedance, inductive_reactance, capacitive_reactance) = match type_index {
-				0 => calculate_impedance_series(l.clone(), c.clone(), omega),
-				1 => calculate_impedance_parallel(l.clone(), c.clone(), omega),
-				_ => unreachable!()
+				let style: NumberStyle = format.parse().map_err(&general)?;
+				let output = |value: BFloat, unit_type: UnitType, target_unit: &SharedString| {
+					output_text(value, &unit_type, target_unit, |value| value.to_styled_string(style)).map_err(&general)
+				};
+
+				ui.set_cap_capacitance(output(capacitance, UnitType::Farad, &c_type)?);
+				ui.set_cap_impedance(match impedance {
+					Some(impedance) => output(impedance, UnitType::Ohm, &z_type)?,
+					None => SharedString::new(),
+				});
+
+				Ok(())
 			};
 
-			let resonant_frequency = calculate_resonant_frequency(l, c);
+			match calcularot() {
+				Ok(()) => {
+					ui.set_cap_error_field(NO_FIELD);
+					save_settings(&ui, &settings, settings_dir.as_deref());
+				}
+				Err((field, message)) => {
+					ui.set_cap_error_field(field);
+					ui.set_cap_error_text(message);
+				}
+			}
+		}
+	});
+
+	//INFO: works out the one dimension picked under "Solve for", writes it into its field in the unit
+	//INFO: picked there and reruns the page with it
+	ui.on_cap_solve({
+		let ui_handle = ui.as_weak();
+		move |shape, unknown, target, target_type, permittivity, area, area_type, separation, separation_type, inner, inner_type, outer, outer_type, length, length_type| {
+			let ui = ui_handle.unwrap();
 
-			let impedance_target = convert_measure(impedance, &UnitType::Ohm, &OHM_BASE_TYPE.to_shared_string(), &imp_type);
-			let xl_target = convert_measure(inductive_reactance, &UnitType::Ohm, &OHM_BASE_TYPE.to_shared_string(), &xl_type);
-			let xc_target = convert_measure(capacitive_reactance, &UnitType::Ohm, &OHM_BASE_TYPE.to_shared_string(), &xc_type);
-			let rf_target = convert_measure(resonant_frequency, &UnitType::Hertz, &HERTZ_BASE_TYPE.to_shared_string(), &rf_type);
+			let solve = || -> Result<(), FieldError> {
+				let shape: CapacitorShape = shape.parse().map_err(field_error(CAP_GENERAL_FIELD))?;
+				let target = parse_to_base(&target, &UnitType::Farad, &target_type).map_err(field_error(CAP_TARGET_FIELD))?;
+				let permittivity = permittivity_input(&permittivity)?;
 
-			ui.set_impedance(impedance_target.as_decimal_string().into());
-			ui.set_inductive_reactance(xl_target.as_decimal_string().into());
-			ui.set_capacitive_reactance(xc_target.as_decimal_string().into());
-			ui.set_resonant_frequency(rf_target.as_decimal_string().into());
+				let meter = |text: &SharedString, unit: &SharedString, field: i32| parse_to_base(text, &UnitType::Meter, unit).map_err(field_error(field));
+				let unknowns = match shape {
+					CapacitorShape::ParallelPlate => PLATE_UNKNOWNS,
+					CapacitorShape::Coaxial => COAX_UNKNOWNS,
+				};
+				let solved = field_error(CAP_TARGET_FIELD);
+
+				let (value, unit_type, unit, set_field): (BFloat, UnitType, &SharedString, fn(&MainWindow, SharedString)) = match (shape, unknowns.iter().position(|name| *name == unknown.as_str())) {
+					(CapacitorShape::ParallelPlate, Some(0)) => {
+						let area = parse_to_base(&area, &UnitType::SquareMeter, &area_type).map_err(field_error(CAP_AREA_FIELD))?;
+						(plate_separation(&area, &permittivity, &target).map_err(&solved)?, UnitType::Meter, &separation_type, MainWindow::set_cap_separation)
+					}
+					(CapacitorShape::ParallelPlate, Some(_)) => {
+						let separation = meter(&separation, &separation_type, CAP_SEPARATION_FIELD)?;
+						(plate_area(&separation, &permittivity, &target).map_err(&solved)?, UnitType::SquareMeter, &area_type, MainWindow::set_cap_area)
+					}
+					(CapacitorShape::Coaxial, Some(0)) => {
+						let inner = meter(&inner, &inner_type, CAP_INNER_FIELD)?;
+						let outer = meter(&outer, &outer_type, CAP_OUTER_FIELD)?;
+						(coax_length(&inner, &outer, &permittivity, &target).map_err(&solved)?, UnitType::Meter, &length_type, MainWindow::set_cap_length)
+					}
+					(CapacitorShape::Coaxial, Some(_)) => {
+						let inner = meter(&inner, &inner_type, CAP_INNER_FIELD)?;
+						let length = meter(&length, &length_type, CAP_LENGTH_FIELD)?;
+						(coax_outer_diameter(&inner, &length, &permittivity, &target).map_err(&solved)?, UnitType::Meter, &outer_type, MainWindow::set_cap_outer)
+					}
+					(_, None) => return Err(solved(CalcError::InvalidCapacitor(format!("'{unknown}' can not be solved for on a {shape} capacitor")))),
+				};
+
+				let text = output_text(value.clone(), &unit_type, unit, |value| value.to_styled_string(NumberStyle::default())).map_err(&solved)?;
+				let rounded = output_text(value, &unit_type, AUTO_UNIT, |value| value.to_styled_string(NumberStyle::Significant(4))).map_err(&solved)?;
+
+				ui.set_cap_solve_text(format!("{} of {rounded}", unknown.to_lowercase()).into());
+				set_field(&ui, text);
+
+				Ok(())
+			};
+
+			match solve() {
+				Ok(()) => {
+					ui.set_cap_error_field(NO_FIELD);
+					ui.invoke_run_cap();
+				}
+				Err((field, message)) => {
+					ui.set_cap_error_field(field);
+					ui.set_cap_error_text(message);
+				}
+			}
+		}
+	});
+
+	//INFO: exports the latest result of the page, the newest history entry of its kind
+	ui.on_export_results({
+		let history = history.clone();
+		move |page, format| {
+			let export = || -> Result<String, Box<dyn Error>> {
+				let format: ExportFormat = format.parse()?;
+
+				let history = history.borrow();
+				let entry = history
+					.iter()
+					.find(|entry| entry.page() == page)
+					.ok_or("nothing to export yet, calcularot first")?;
+
+				export_entry(entry, format)
+			};
+
+			match export() {
+				Ok(status) => status.into(),
+				Err(error) => format!("export failed: {error}").into(),
+			}
+		}
+	});
+
+	ui.on_history_recall({
+		let ui_handle = ui.as_weak();
+		let history = history.clone();
+		let type1 = input1_type.clone();
+		let type2 = input2_type.clone();
+		move |index| {
+			let ui = ui_handle.unwrap();
+
+			let Some(entry) = history.borrow().get(index as usize).cloned() else {
+				return 0;
+			};
+
+			match &entry {
+				HistoryEntry::Lc(record) => restore_lc(&ui, record, &type1, &type2),
+				HistoryEntry::Imp(record) => restore_imp(&ui, record),
+				HistoryEntry::Net(record) => restore_net(&ui, record),
+			}
+
+			entry.page()
+		}
+	});
+
+	ui.on_history_clear({
+		let history = history.clone();
+		let history_model = history_model.clone();
+		move || {
+			history.borrow_mut().clear();
+			history_model.set_vec(Vec::new());
 		}
 	});
 
 	ui.run()?;
 
+	save_settings(&ui, &settings, settings_dir.as_deref());
+
 	Ok(())
 }
 
-fn handle_combobox_changed(new_value: SharedString, unit_type: Rc<RefCell<UnitType>>, other_type: Rc<RefCell<UnitType>>, combo_func: impl Fn(SharedString)) {
-	let new_type = get_unit_group(&new_value);
-	
-	if *unit_type.borrow() == new_type {
-		return
+fn restore_lc(ui: &MainWindow, record: &LcRecord, type1: &RefCell<UnitType>, type2: &RefCell<UnitType>) {
+	//INFO: set the groups first, so the combo change handlers don't clear each other
+	type1.replace(get_unit_group(&record.input1_unit));
+	type2.replace(get_unit_group(&record.input2_unit));
+
+	ui.set_lc_input1_combo_text(record.input1_unit.to_shared_string());
+	ui.set_lc_input2_combo_text(record.input2_unit.to_shared_string());
+	ui.set_lc_output_combo_text(record.output_unit.to_shared_string());
+	ui.set_lc_input1_text(record.input1_text.to_shared_string());
+	ui.set_lc_input2_text(record.input2_text.to_shared_string());
+}
+
+fn restore_imp(ui: &MainWindow, record: &ImpRecord) {
+	ui.set_inductance(record.inductance.to_shared_string());
+	ui.set_capacitance(record.capacitance.to_shared_string());
+	ui.set_frequency(record.frequency.to_shared_string());
+	ui.set_resistance(record.resistance.to_shared_string());
+	ui.set_l_unit(record.l_unit.to_shared_string());
+	ui.set_c_unit(record.c_unit.to_shared_string());
+	ui.set_f_unit(record.f_unit.to_shared_string());
+	ui.set_r_unit(record.r_unit.to_shared_string());
+	ui.invoke_set_imp_circuit(record.circuit);
+}
+
+fn restore_net(ui: &MainWindow, record: &NetRecord) {
+	ui.set_network_text(record.network.to_shared_string());
+	ui.set_net_frequency(record.frequency.to_shared_string());
+	ui.set_net_f_unit(record.f_unit.to_shared_string());
+}
+
+fn restore_coil(ui: &MainWindow, coil: &CoilSettings) {
+	ui.set_coil_shape(coil.shape.to_shared_string());
+	ui.set_coil_turns(coil.turns.to_shared_string());
+	ui.set_coil_diameter(coil.diameter.to_shared_string());
+	ui.set_coil_diameter_unit(coil.diameter_unit.to_shared_string());
+	ui.set_coil_length(coil.length.to_shared_string());
+	ui.set_coil_length_unit(coil.length_unit.to_shared_string());
+	ui.set_coil_wire(coil.wire.to_shared_string());
+	ui.set_coil_wire_unit(coil.wire_unit.to_shared_string());
+	ui.set_coil_target(coil.target.to_shared_string());
+	ui.set_coil_target_unit(coil.target_unit.to_shared_string());
+}
+
+fn restore_core(ui: &MainWindow, core: &CoreSettings, cores: &[Core]) {
+	ui.set_core_name(core.name.to_shared_string());
+	ui.set_core_al(core.al.to_shared_string());
+	ui.set_core_al_unit(core.al_unit.to_shared_string());
+	ui.set_core_turns(core.turns.to_shared_string());
+	ui.set_core_l_unit(core.l_unit.to_shared_string());
+	ui.set_core_target(core.target.to_shared_string());
+	ui.set_core_target_unit(core.target_unit.to_shared_string());
+	ui.set_core_info(cores.iter().find(|entry| entry.name == core.name).and_then(|entry| core_info(entry).ok()).unwrap_or_default());
+}
+
+fn restore_cap(ui: &MainWindow, cap: &CapSettings) {
+	ui.set_cap_shape(cap.shape.to_shared_string());
+	ui.set_cap_dielectric(cap.dielectric.to_shared_string());
+	ui.set_cap_permittivity(cap.permittivity.to_shared_string());
+	ui.set_cap_area(cap.area.to_shared_string());
+	ui.set_cap_area_unit(cap.area_unit.to_shared_string());
+	ui.set_cap_separation(cap.separation.to_shared_string());
+	ui.set_cap_separation_unit(cap.separation_unit.to_shared_string());
+	ui.set_cap_inner(cap.inner.to_shared_string());
+	ui.set_cap_inner_unit(cap.inner_unit.to_shared_string());
+	ui.set_cap_outer(cap.outer.to_shared_string());
+	ui.set_cap_outer_unit(cap.outer_unit.to_shared_string());
+	ui.set_cap_length(cap.length.to_shared_string());
+	ui.set_cap_length_unit(cap.length_unit.to_shared_string());
+	ui.set_cap_target(cap.target.to_shared_string());
+	ui.set_cap_target_unit(cap.target_unit.to_shared_string());
+	ui.set_cap_unknown(cap.unknown.to_shared_string());
+}
+
+//INFO: reads the pages back into the settings, so the next launch starts where this one stopped
+fn save_settings(ui: &MainWindow, settings: &RefCell<Settings>, settings_dir: Option<&Path>) {
+	let mut settings = settings.borrow_mut();
+
+	settings.page = ui.get_page();
+	settings.music_muted = ui.get_music_muted();
+	settings.music_on_start = ui.get_music_on_start();
+	settings.music_volume = ui.get_music_volume();
+	settings.precision = precision();
+	settings.rounding = rounding_mode_name().to_string();
+	settings.e_series = chosen_series(ui).map(|series| series.to_string()).unwrap_or_default();
+
+	settings.lc.input1_text = ui.get_lc_input1_text().to_string();
+	settings.lc.input1_unit = ui.get_lc_input1_combo_text().to_string();
+	settings.lc.input2_text = ui.get_lc_input2_text().to_string();
+	settings.lc.input2_unit = ui.get_lc_input2_combo_text().to_string();
+	settings.lc.output_unit = ui.get_lc_output_combo_text().to_string();
+
+	settings.imp.inductance = ui.get_inductance().to_string();
+	settings.imp.capacitance = ui.get_capacitance().to_string();
+	settings.imp.frequency = ui.get_frequency().to_string();
+	settings.imp.resistance = ui.get_resistance().to_string();
+	settings.imp.l_unit = ui.get_l_unit().to_string();
+	settings.imp.c_unit = ui.get_c_unit().to_string();
+	settings.imp.f_unit = ui.get_f_unit().to_string();
+	settings.imp.r_unit = ui.get_r_unit().to_string();
+	settings.imp.circuit = ui.invoke_get_imp_circuit();
+
+	settings.net.network = ui.get_network_text().to_string();
+	settings.net.frequency = ui.get_net_frequency().to_string();
+	settings.net.f_unit = ui.get_net_f_unit().to_string();
+
+	settings.coil.shape = ui.get_coil_shape().to_string();
+	settings.coil.turns = ui.get_coil_turns().to_string();
+	settings.coil.diameter = ui.get_coil_diameter().to_string();
+	settings.coil.diameter_unit = ui.get_coil_diameter_unit().to_string();
+	settings.coil.length = ui.get_coil_length().to_string();
+	settings.coil.length_unit = ui.get_coil_length_unit().to_string();
+	settings.coil.wire = ui.get_coil_wire().to_string();
+	settings.coil.wire_unit = ui.get_coil_wire_unit().to_string();
+	settings.coil.target = ui.get_coil_target().to_string();
+	settings.coil.target_unit = ui.get_coil_target_unit().to_string();
+
+	settings.core.name = ui.get_core_name().to_string();
+	settings.core.al = ui.get_core_al().to_string();
+	settings.core.al_unit = ui.get_core_al_unit().to_string();
+	settings.core.turns = ui.get_core_turns().to_string();
+	settings.core.l_unit = ui.get_core_l_unit().to_string();
+	settings.core.target = ui.get_core_target().to_string();
+	settings.core.target_unit = ui.get_core_target_unit().to_string();
+
+	settings.cap.shape = ui.get_cap_shape().to_string();
+	settings.cap.dielectric = ui.get_cap_dielectric().to_string();
+	settings.cap.permittivity = ui.get_cap_permittivity().to_string();
+	settings.cap.area = ui.get_cap_area().to_string();
+	settings.cap.area_unit = ui.get_cap_area_unit().to_string();
+	settings.cap.separation = ui.get_cap_separation().to_string();
+	settings.cap.separation_unit = ui.get_cap_separation_unit().to_string();
+	settings.cap.inner = ui.get_cap_inner().to_string();
+	settings.cap.inner_unit = ui.get_cap_inner_unit().to_string();
+	settings.cap.outer = ui.get_cap_outer().to_string();
+	settings.cap.outer_unit = ui.get_cap_outer_unit().to_string();
+	settings.cap.length = ui.get_cap_length().to_string();
+	settings.cap.length_unit = ui.get_cap_length_unit().to_string();
+	settings.cap.target = ui.get_cap_target().to_string();
+	settings.cap.target_unit = ui.get_cap_target_unit().to_string();
+	settings.cap.unknown = ui.get_cap_unknown().to_string();
+
+	//INFO: shown on the menu, a print would go nowhere on Windows or Android
+	match settings.save(settings_dir) {
+		Ok(()) => ui.set_settings_error(SharedString::new()),
+		Err(error) => ui.set_settings_error(format!("settings could not be saved: {error}").into()),
 	}
+}
 
-	if new_type == *other_type.borrow() {
-		combo_func("".to_shared_string());
+//INFO: "toroid, μ 10, 12.7 × 7.7 × 4.83 mm, A_L 4.9 nH", outer and inner diameter then height
+fn core_info(core: &Core) -> Result<SharedString, CalcError> {
+	let text = |value: &BFloat, unit_type: UnitType, unit: &str| output_text(value.clone(), &unit_type, unit, |value| value.to_styled_string(NumberStyle::Significant(4)));
+
+	Ok(format!(
+		"{}, μ {}, {} × {} × {} mm, A_L {} nH",
+		core.kind,
+		core.permeability.to_styled_string(NumberStyle::Significant(4)),
+		text(&core.outer_diameter, UnitType::Meter, "mm")?,
+		text(&core.inner_diameter, UnitType::Meter, "mm")?,
+		text(&core.height, UnitType::Meter, "mm")?,
+		text(&core.al, UnitType::Henry, "nH")?,
+	).into())
+}
+
+//INFO: εr, vacuum is the least a dielectric can be
+fn permittivity_input(text: &str) -> Result<BFloat, FieldError> {
+	let permittivity: BFloat = text.parse().map_err(field_error(CAP_PERMITTIVITY_FIELD))?;
+
+	if permittivity < BFloat::from(1) {
+		return Err(field_error(CAP_PERMITTIVITY_FIELD)(CalcError::InvalidCapacitor("εr can not be below 1".to_string())));
 	}
 
-	unit_type.replace(new_type);
+	Ok(permittivity)
+}
+
+//INFO: index 0 of the E series combo is Off
+fn chosen_series(ui: &MainWindow) -> Option<ESeries> {
+	let index = ui.get_e_series_index() as usize;
+
+	E_SERIES_NAMES.get(index.checked_sub(1)?)?.parse().ok()
 }
 
-fn get_full_value_list(l_str: &SharedString, c_str: &SharedString, f_str: &SharedString, l_type: &SharedString, c_type: &SharedString, f_type: &SharedString, ui: &MainWindow) -> Option<(BFloat, BFloat, BFloat)> {
-	let l: BFloat;
-	let c: BFloat;
-	let f: BFloat;
+//INFO: the preferred parts either side of a computed L or C, and how far each moves f0 off target
+fn snap_text(series: ESeries, value: &BFloat, unit_type: UnitType, other: &BFloat, f0: &BFloat) -> Result<SharedString, CalcError> {
+	let (lower, upper) = series.neighbours(value)?;
+
+	//INFO: f0 = 1/(2π√(LC)) is symmetric, the part goes in either argument
+	let part = |part: &BFloat| -> Result<String, CalcError> {
+		let error = relative_error(&lc_to_f0(part.clone(), other.clone()), f0) * BFloat::from(100);
+		let sign = if error.0.is_negative() { "" } else { "+" };
+		let text = output_text(part.clone(), &unit_type, AUTO_UNIT, |value| value.to_styled_string(NumberStyle::Significant(3)))?;
+
+		Ok(format!("{text} (f0 {sign}{} %)", error.to_styled_string(NumberStyle::Decimals(2))))
+	};
 
-	let l_maybe = BFloat::from_str(&l_str).unwrap_or(BFloat::nan());
-	let c_maybe = BFloat::from_str(&c_str).unwrap_or(BFloat::nan());
-	let f_maybe = BFloat::from_str(&f_str).unwrap_or(BFloat::nan());
+	Ok(format!("{series}: lower {}, upper {}", part(&lower)?, part(&upper)?).into())
+}
 
-	let l_nan = l_maybe.0.is_nan();
-	let c_nan = c_maybe.0.is_nan();
-	let f_nan = f_maybe.0.is_nan();
+//INFO: the two LC inputs with their tolerances, and the groups of input 1, input 2 and the output
+fn lc_tolerance_parts(ui: &MainWindow, tolerance1: &str, tolerance2: &str) -> Result<([Toleranced; 2], [UnitType; 3]), FieldError> {
+	let input1_type = ui.get_lc_input1_combo_text();
+	let input2_type = ui.get_lc_input2_combo_text();
+	let output_type = ui.get_lc_output_combo_text();
 
-	let nans = l_nan as u8 + c_nan as u8 + f_nan as u8;
+	let input1_group = get_unit_group(&input1_type);
+	let input2_group = get_unit_group(&input2_type);
+	let output_group = get_unit_group(&output_type);
 
-	if nans > 1 {
-		return None;
+	if input1_group == UnitType::NotSelected {
+		return Err(field_error(LC_INPUT1_FIELD)(CalcError::UnitNotSelected));
+	}
+	if input2_group == UnitType::NotSelected {
+		return Err(field_error(LC_INPUT2_FIELD)(CalcError::UnitNotSelected));
 	}
+	if output_group == UnitType::NotSelected {
+		return Err(field_error(LC_OUTPUT_FIELD)(CalcError::UnitNotSelected));
+	}
+
+	let input1_base = parse_to_base(&ui.get_lc_input1_text(), &input1_group, &input1_type).map_err(field_error(LC_INPUT1_FIELD))?;
+	let input2_base = parse_to_base(&ui.get_lc_input2_text(), &input2_group, &input2_type).map_err(field_error(LC_INPUT2_FIELD))?;
+
+	let parts = [
+		Toleranced::new(input1_base, tolerance_input(tolerance1).map_err(field_error(LC_TOLERANCE_FIELD))?),
+		Toleranced::new(input2_base, tolerance_input(tolerance2).map_err(field_error(LC_TOLERANCE_FIELD))?),
+	];
+
+	Ok((parts, [input1_group, input2_group, output_group]))
+}
 
-	let l_maybe_base = match l_nan {
-		true => None,
-		false => Some(convert_to_base(l_maybe, &UnitType::Henry, l_type)),
+//INFO: the LC output for one draw of the inputs, an input passes straight through when it is the output
+fn lc_evaluate(values: &[BFloat], groups: &[UnitType; 3]) -> Result<Vec<BFloat>, CalcError> {
+	let [input1_group, input2_group, output_group] = *groups;
+
+	let result = if input1_group == output_group {
+		values[0].clone()
+	} else if input2_group == output_group {
+		values[1].clone()
+	} else {
+		calculate_lc(values[0].clone(), values[1].clone(), input1_group, output_group)?
 	};
 
-	let c_maybe_base = match c_nan {
-		true => None,
-		false => Some(convert_to_base(c_maybe, &UnitType::Farad, c_type)),
+	Ok(vec![result])
+}
+
+//INFO: L, C and R when there is one, with their tolerances, the circuit and the ω |Z| is taken at
+fn imp_tolerance_parts(ui: &MainWindow, tolerance_l: &str, tolerance_c: &str, tolerance_r: &str) -> Result<(Vec<Toleranced>, i32, BFloat), FieldError> {
+	let type_index = ui.invoke_get_imp_circuit();
+
+	if type_index == -1 {
+		return Err((IMP_GENERAL_FIELD, "select series or parallel".to_shared_string()));
+	}
+
+	let (l, c, f) = get_full_value_list(&ui.get_inductance(), &ui.get_capacitance(), &ui.get_frequency(), &ui.get_l_unit(), &ui.get_c_unit(), &ui.get_f_unit(), ui)?;
+	let r = optional_input(&ui.get_resistance(), &UnitType::Ohm, &ui.get_r_unit()).map_err(field_error(IMP_R_FIELD))?;
+
+	let tolerance = |text: &str| tolerance_input(text).map_err(field_error(IMP_TOLERANCE_FIELD));
+
+	let mut parts = vec![Toleranced::new(l, tolerance(tolerance_l)?), Toleranced::new(c, tolerance(tolerance_c)?)];
+	if let Some(r) = r {
+		parts.push(Toleranced::new(r, tolerance(tolerance_r)?));
+	}
+
+	Ok((parts, type_index, get_omega(f)))
+}
+
+//INFO: f0 and |Z| for one draw of L, C and the R that is there or not, by how many values came
+fn imp_evaluate(values: &[BFloat], type_index: i32, omega: &BFloat) -> Vec<BFloat> {
+	let (l, c, r) = (values[0].clone(), values[1].clone(), values.get(2).cloned());
+
+	let impedance = match type_index {
+		0 => calculate_impedance_series(r, l.clone(), c.clone(), omega.clone()),
+		_ => calculate_impedance_parallel(r, l.clone(), c.clone(), omega.clone()),
 	};
-	
-	let f_maybe_base = match f_nan {
-		true => None,
-		false => Some(convert_to_base(f_maybe, &UnitType::Hertz, f_type)),
+
+	vec![calculate_resonant_frequency(l, c), impedance.magnitude()]
+}
+
+fn quantity_name(unit_type: UnitType) -> &'static str {
+	match unit_type {
+		UnitType::Hertz => "f0",
+		UnitType::Henry => "L",
+		_ => "C",
+	}
+}
+
+//INFO: a tolerance typed in %, empty for an exact part
+fn tolerance_input(text: &str) -> Result<BFloat, CalcError> {
+	let tolerance = match text.trim() {
+		"" => return Ok(BFloat::from(0)),
+		text => text.parse::<BFloat>()?,
 	};
 
-	l = match l_maybe_base.clone() {
-		Some(num) => num,
-		None => {
-			let value_base = cf0_to_l(c_maybe_base.clone().unwrap(), f_maybe_base.clone().unwrap());
+	if tolerance.0.is_negative() {
+		return Err(CalcError::InvalidAnalysis(format!("tolerance '{text}' is below 0 %")));
+	}
+
+	Ok(tolerance / BFloat::from(100))
+}
+
+fn run_settings(samples: &str, distribution: &str) -> Result<(usize, Distribution), CalcError> {
+	let count = samples
+		.trim()
+		.parse()
+		.map_err(|_| CalcError::InvalidAnalysis(format!("'{samples}' is not a number of samples")))?;
 
-			let value = convert_measure(value_base, &UnitType::Henry, &HENRY_BASE_TYPE.to_shared_string(), l_type);
+	Ok((count, distribution.parse()?))
+}
 
-			ui.set_inductance(value.as_decimal_string().to_shared_string());
+//INFO: up to MAX_SAMPLES evaluations take seconds at a high precision, they run on a worker so the window
+//INFO: keeps drawing and Android does not kill it as hung; the statistics come back to the event loop,
+//INFO: images are drawn there as they cannot leave it
+fn spawn_monte_carlo(
+	ui_handle: Weak<MainWindow>,
+	parts: Vec<Toleranced>,
+	distribution: Distribution,
+	samples: usize,
+	evaluate: impl Fn(&[BFloat]) -> Result<Vec<BFloat>, CalcError> + Send + 'static,
+	show: impl FnOnce(&MainWindow, Result<Vec<Statistics>, CalcError>) + Send + 'static,
+) {
+	thread::spawn(move || {
+		let statistics = monte_carlo(&parts, distribution, samples, &mut Rng::from_clock(), evaluate);
 
-			value
-		},
+		//INFO: an error here means the window is gone, there is nothing left to show the result in
+		let _ = slint::invoke_from_event_loop(move || {
+			if let Some(ui) = ui_handle.upgrade() {
+				show(&ui, statistics);
+			}
+		});
+	});
+}
+
+//INFO: one line of mean, σ and range per result, next to its histogram
+fn spread_lines(results: &[(&str, UnitType)], statistics: &[Statistics]) -> Result<(Vec<SharedString>, Vec<Image>), CalcError> {
+	let text = |value: &BFloat, unit_type: &UnitType| output_text(value.clone(), unit_type, AUTO_UNIT, |value| value.to_styled_string(NumberStyle::Significant(4)));
+
+	let mut lines = Vec::new();
+	let mut histograms = Vec::new();
+
+	for ((name, unit_type), statistics) in results.iter().zip(statistics) {
+		let spread = statistics.std_dev.clone() / statistics.mean.clone() * BFloat::from(100);
+
+		lines.push(format!(
+			"{name}: mean {}, σ {} ({} %), {} to {}",
+			text(&statistics.mean, unit_type)?,
+			text(&statistics.std_dev, unit_type)?,
+			spread.abs().to_styled_string(NumberStyle::Significant(2)),
+			text(&statistics.min, unit_type)?,
+			text(&statistics.max, unit_type)?,
+		).into());
+		histograms.push(render_histogram(&statistics.histogram));
+	}
+
+	Ok((lines, histograms))
+}
+
+//INFO: per result the corner range with the part edges that reach it, then its sensitivities
+fn worst_case_lines(part_names: &[&str], results: &[(&str, UnitType)], sensitivity: &[Vec<BFloat>], extremes: &[Extremes]) -> Result<Vec<SharedString>, CalcError> {
+	let text = |value: &BFloat, unit_type: &UnitType| output_text(value.clone(), unit_type, AUTO_UNIT, |value| value.to_styled_string(NumberStyle::Significant(4)));
+
+	let percent = |value: &BFloat, nominal: &BFloat| {
+		let change = relative_error(value, nominal) * BFloat::from(100);
+		let sign = if change.0.is_negative() { "" } else { "+" };
+		format!("{sign}{} %", change.to_styled_string(NumberStyle::Decimals(2)))
 	};
 
-	c = match c_maybe_base.clone() {
-		Some(num) => num,
-		None => {
-			let value_base = lf0_to_c(l.clone(), f_maybe_base.clone().unwrap());
+	//INFO: a part left at nominal does not move the result and is left out
+	let corner = |edges: &[i8]| {
+		let parts = part_names
+			.iter()
+			.zip(edges)
+			.filter(|(_, edge)| **edge != 0)
+			.map(|(name, edge)| format!("{name}{}", if *edge > 0 { "+" } else { "-" }))
+			.collect::<Vec<String>>();
+
+		match parts.is_empty() {
+			true => "nominal".to_string(),
+			false => parts.join(" "),
+		}
+	};
 
-			let value = convert_measure(value_base, &UnitType::Farad, &FARAD_BASE_TYPE.to_shared_string(), &c_type);
+	let mut lines = Vec::new();
 
-			ui.set_capacitance(value.as_decimal_string().to_shared_string());
+	for (((name, unit_type), extremes), sensitivity) in results.iter().zip(extremes).zip(sensitivity) {
+		lines.push(format!(
+			"{name}: nominal {}, {} ({}, {}) to {} ({}, {})",
+			text(&extremes.nominal, unit_type)?,
+			text(&extremes.min, unit_type)?,
+			percent(&extremes.min, &extremes.nominal),
+			corner(&extremes.min_corner),
+			text(&extremes.max, unit_type)?,
+			percent(&extremes.max, &extremes.nominal),
+			corner(&extremes.max_corner),
+		).into());
+
+		let weights = part_names
+			.iter()
+			.zip(sensitivity)
+			.map(|(part, weight)| format!("{part} {}", weight.to_styled_string(NumberStyle::Significant(3))))
+			.collect::<Vec<String>>();
+
+		lines.push(format!("{name} sensitivity, % per % of each part: {}", weights.join(", ")).into());
+	}
+
+	Ok(lines)
+}
+
+fn precision_digits_text() -> SharedString {
+	format!("≈ {} significant digits", significant_digits(precision())).into()
+}
+
+fn push_history(history: &RefCell<Vec<HistoryEntry>>, history_model: &VecModel<HistoryItem>, entry: HistoryEntry) {
+	history_model.insert(0, HistoryItem {
+		title: entry.title().into(),
+		summary: entry.summary().into(),
+	});
+	history.borrow_mut().insert(0, entry);
+}
+
+fn handle_combobox_changed(new_value: SharedString, unit_type: Rc<RefCell<UnitType>>, other_type: Rc<RefCell<UnitType>>, combo_func: impl Fn(SharedString)) {
+	let new_type = get_unit_group(&new_value);
+	
+	if *unit_type.borrow() == new_type {
+		return
+	}
 
-			value
+	if new_type == *other_type.borrow() {
+		combo_func("".to_shared_string());
+	}
+
+	unit_type.replace(new_type);
+}
+
+//INFO: the number style picked next to output `index`
+fn format_style(formats: &ModelRc<SharedString>, index: usize) -> Result<NumberStyle, CalcError> {
+	formats.row_data(index).unwrap_or_default().parse()
+}
+
+//INFO: the value in `target_unit`, followed by the picked prefix when the target is Auto
+fn output_text(value: BFloat, unit_type: &UnitType, target_unit: &str, to_text: impl Fn(&BFloat) -> String) -> Result<SharedString, CalcError> {
+	let (value, auto_label) = convert_for_output(value, unit_type, target_unit)?;
+
+	Ok(match auto_label {
+		Some(label) => format!("{} {label}", to_text(&value)).into(),
+		None => to_text(&value).into(),
+	})
+}
+
+//INFO: derives the one missing value out of L, C and f, writing it back into its field
+fn get_full_value_list(l_str: &SharedString, c_str: &SharedString, f_str: &SharedString, l_type: &SharedString, c_type: &SharedString, f_type: &SharedString, ui: &MainWindow) -> Result<(BFloat, BFloat, BFloat), FieldError> {
+	let l_maybe = optional_input(l_str, &UnitType::Henry, l_type).map_err(field_error(IMP_L_FIELD))?;
+	let c_maybe = optional_input(c_str, &UnitType::Farad, c_type).map_err(field_error(IMP_C_FIELD))?;
+	let f_maybe = optional_input(f_str, &UnitType::Hertz, f_type).map_err(field_error(IMP_F_FIELD))?;
+
+	//INFO: the derived value goes back into its field to 10 significant digits, like a value sent from the core page
+	let derived_text = |value: &BFloat| value.to_styled_string(NumberStyle::default());
+
+	let values = match (l_maybe, c_maybe, f_maybe) {
+		(Some(l), Some(c), Some(f)) => (l, c, f),
+		(None, Some(c), Some(f)) => {
+			let l = cf0_to_l(c.clone(), f.clone());
+
+			ui.set_inductance(output_text(l.clone(), &UnitType::Henry, l_type, derived_text).map_err(field_error(IMP_L_FIELD))?);
+
+			(l, c, f)
 		},
-	};
+		(Some(l), None, Some(f)) => {
+			let c = lf0_to_c(l.clone(), f.clone());
 
-	f = match f_maybe_base {
-		Some(num) => num,
-		None => {
-			let value_base = lc_to_f0(l_maybe_base.unwrap(), c_maybe_base.unwrap());
+			ui.set_capacitance(output_text(c.clone(), &UnitType::Farad, c_type, derived_text).map_err(field_error(IMP_C_FIELD))?);
 
-			let value = convert_measure(value_base, &UnitType::Hertz, &HERTZ_BASE_TYPE.to_shared_string(), &f_type);
+			(l, c, f)
+		},
+		(Some(l), Some(c), None) => {
+			let f = lc_to_f0(l.clone(), c.clone());
 
-			ui.set_frequency(value.as_decimal_string().to_shared_string());
+			ui.set_frequency(output_text(f.clone(), &UnitType::Hertz, f_type, derived_text).map_err(field_error(IMP_F_FIELD))?);
 
-			value
+			(l, c, f)
 		},
+		_ => return Err(field_error(IMP_GENERAL_FIELD)(CalcError::MissingInputs)),
+	};
+
+	Ok(values)
+}
+
+//INFO: the coil dimensions in meters, a spiral has no length and an empty one winds a single layer close
+fn coil_geometry(shape: &str, diameter: &str, diameter_type: &str, length: &str, length_type: &str, wire: &str, wire_type: &str) -> Result<CoilGeometry, FieldError> {
+	let shape: CoilShape = shape.parse().map_err(field_error(COIL_GENERAL_FIELD))?;
+
+	let diameter = parse_to_base(diameter, &UnitType::Meter, diameter_type).map_err(field_error(COIL_DIAMETER_FIELD))?;
+	let wire = parse_to_base(wire, &UnitType::Meter, wire_type).map_err(field_error(COIL_WIRE_FIELD))?;
+	let length = match shape {
+		CoilShape::FlatSpiral => None,
+		_ => optional_input(length, &UnitType::Meter, length_type).map_err(field_error(COIL_LENGTH_FIELD))?,
 	};
 
-	Some((l, c, f))
+	Ok(CoilGeometry { shape, diameter, length, wire })
+}
+
+//INFO: an empty field is a value to be derived, anything else must parse
+fn optional_input(text: &str, unit_type: &UnitType, unit_label: &str) -> Result<Option<BFloat>, CalcError> {
+	match parse_to_base(text, unit_type, unit_label) {
+		Ok(value) => Ok(Some(value)),
+		Err(CalcError::EmptyInput) => Ok(None),
+		Err(error) => Err(error),
+	}
+}
+
+fn field_error(field: i32) -> impl Fn(CalcError) -> FieldError {
+	move |error| (field, error.to_shared_string())
 }
@@ -3,61 +3,52 @@
 #![windows_subsystem = "windows"]
 
 mod not_android;
-mod units;
-mod types;
+mod cli;
 mod utils;
-mod calculations;
+mod catalog;
+mod export;
+mod history;
+mod music;
+mod plot;
+mod settings;
 mod traits;
-mod consts;
-mod conversions;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    not_android::run_app()
-}
-
-#[cfg(test)]
-mod tests {
-	use num_traits::{Zero, One};
-
-use crate::types::BFloat;
-
-	fn f(v: f64) -> BFloat {
-        BFloat::from(v) // convenience: convert f64 → MyFloat
-    }
+use std::process::ExitCode;
 
-    #[test]
-    fn test_basic_arithmetic() {
-        let two = f(2.0);
-        let three = f(3.0);
+fn main() -> ExitCode {
+	let args: Vec<String> = std::env::args().skip(1).collect();
 
-        assert_eq!(two.clone() + three.clone(), f(5.0));
-        assert_eq!(two.clone() * three.clone(), f(6.0));
-        assert_eq!(three.clone() - two.clone(), f(1.0));
-    }
+	let result = match args.is_empty() {
+		true => not_android::run_app(),
+		false => {
+			#[cfg(windows)]
+			attach_console();
 
-	#[test]
-	fn zero_functions() {
-		let zero = f(0.0);
-		let mut one = f(1.0);
-		
-		assert_eq!(zero.is_zero(), true);
-		assert_eq!(one.is_zero(), false);
+			cli::run(args)
+		}
+	};
 
-		one.set_zero();
-
-		assert_eq!(one.is_zero(), true);
+	match result {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(error) => {
+			eprintln!("{error}");
+			ExitCode::FAILURE
+		}
 	}
+}
 
-	#[test]
-	fn one_functions() {
-		let mut zero = f(0.0);
-		let one = f(1.0);
-		
-		assert_eq!(zero.is_one(), false);
-		assert_eq!(one.is_one(), true);
+//INFO: the windows subsystem starts without a console, the CLI writes to the one it was started from
+#[cfg(windows)]
+fn attach_console() {
+	const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
 
-		zero.set_one();
+	#[link(name = "kernel32")]
+	unsafe extern "system" {
+		fn AttachConsole(process_id: u32) -> i32;
+	}
 
-		assert_eq!(zero.is_one(), true);
+	//INFO: fails when started from Explorer, there is no console to write to then anyway
+	unsafe {
+		AttachConsole(ATTACH_PARENT_PROCESS);
 	}
 }
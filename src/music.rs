@@ -0,0 +1,129 @@
+use std::{cell::RefCell, error::Error, sync::{Arc, Mutex, MutexGuard}};
+
+use rodio::{OutputStreamBuilder, Sink, Source};
+
+//INFO: the error of a music that cannot play, sent from the thread it fails on
+type Report = Arc<dyn Fn(String) + Send + Sync>;
+
+//INFO: what the music should do, shared with the thread that plays it
+struct State {
+	sink: Option<Arc<Sink>>,
+	running: bool,
+	playing: bool,
+	volume: f32,
+}
+
+impl State {
+	fn apply(&self) {
+		if let Some(sink) = self.sink.as_ref() {
+			sink.set_volume(self.volume);
+
+			match self.playing {
+				true => sink.play(),
+				false => sink.pause(),
+			}
+		}
+	}
+}
+
+//INFO: the looping background music; nothing is opened or decoded until it is first asked to play, then the
+//INFO: sink lives on its own thread and is put into the shared state once it plays
+pub struct Music {
+	start: Box<dyn Fn(Report)>,
+	report: RefCell<Report>,
+	state: Arc<Mutex<State>>,
+}
+
+impl Music {
+	pub fn new<S, F>(load: F, volume: f32) -> Music
+	where
+		S: Source + Send + 'static,
+		F: Fn() -> Result<S, Box<dyn Error>> + Send + Sync + 'static,
+	{
+		let load = Arc::new(load);
+		let state = Arc::new(Mutex::new(State {
+			sink: None,
+			running: false,
+			playing: false,
+			volume: volume.clamp(0.0, 1.0),
+		}));
+
+		let start = {
+			let state = state.clone();
+			move |report: Report| {
+				let load = load.clone();
+				let state = state.clone();
+
+				std::thread::spawn(move || {
+					let res = || -> Result<(), Box<dyn Error>> {
+						let source = load()?;
+
+						//INFO: the stream stops playing when dropped, so it stays on this thread
+						let stream_handle = OutputStreamBuilder::open_default_stream()?;
+						let sink = Arc::new(Sink::connect_new(stream_handle.mixer()));
+
+						//INFO: under the lock, a mute or volume change made while the stream opened is not lost
+						{
+							let mut state = lock(&state);
+							state.sink = Some(sink.clone());
+							state.apply();
+						}
+
+						sink.append(source);
+						sink.sleep_until_end();
+
+						Ok(())
+					}();
+
+					//INFO: the next time the music is asked to play it starts again
+					{
+						let mut state = lock(&state);
+						state.sink = None;
+						state.running = false;
+					}
+
+					if let Err(error) = res {
+						report(error.to_string());
+					}
+				});
+			}
+		};
+
+		Music {
+			start: Box::new(start),
+			report: RefCell::new(Arc::new(|_| ())),
+			state,
+		}
+	}
+
+	//INFO: where a failure to play goes, set before the music first plays
+	pub fn on_error(&self, report: impl Fn(String) + Send + Sync + 'static) {
+		self.report.replace(Arc::new(report));
+	}
+
+	pub fn set_playing(&self, playing: bool) {
+		lock(&self.state).playing = playing;
+		self.apply();
+	}
+
+	pub fn set_volume(&self, volume: f32) {
+		lock(&self.state).volume = volume.clamp(0.0, 1.0);
+		self.apply();
+	}
+
+	fn apply(&self) {
+		let mut state = lock(&self.state);
+
+		if state.playing && !state.running {
+			state.running = true;
+			(self.start)(self.report.borrow().clone());
+		}
+
+		state.apply();
+	}
+}
+
+//INFO: a thread that panicked while holding the lock leaves the state usable
+fn lock(state: &Mutex<State>) -> MutexGuard<'_, State> {
+	state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -1,31 +1,23 @@
 use std::error::Error;
-use rodio::{OutputStreamBuilder, Sink, Decoder};
+use rodio::{decoder::LoopedDecoder, Decoder};
+
+use crate::music::Music;
+use crate::settings::{config_dir, Settings};
 
 #[path = "logic.rs"]
 mod logic;
 
 pub fn run_app() -> Result<(), Box<dyn Error>> {
-    std::thread::spawn(||{
-        let res = play_background_music();
-		if let Err(error) = res {
-			println!("{}", error);
-		}
-    });
+	let settings_dir = config_dir();
+	let settings = Settings::load(settings_dir.as_deref());
+
+	let music = Music::new(load_background_music, settings.music_volume);
 
-    logic::start_ui()
+    logic::start_ui(settings, settings_dir, music)
 }
 
-fn play_background_music() -> Result<(), Box<dyn Error>> {
+fn load_background_music() -> Result<LoopedDecoder<std::fs::File>, Box<dyn Error>> {
     let file = std::fs::File::open("assets/music.mp3")?;
 
-    let source = Decoder::new_looped(file)?;
-
-    let stream_handle = OutputStreamBuilder::open_default_stream()?;
-    let sink = Sink::connect_new(stream_handle.mixer());
-
-    sink.append(source);
-
-    sink.sleep_until_end();
-
-    Ok(())
+    Ok(Decoder::new_looped(file)?)
 }
@@ -0,0 +1,253 @@
+use calcularoty_core::sweep::SweepPoint;
+use calcularoty_core::types::BFloat;
+
+use slint::{Image, Rgb8Pixel, SharedPixelBuffer};
+
+pub const PLOT_WIDTH: u32 = 640;
+pub const PLOT_HEIGHT: u32 = 480;
+
+pub const HISTOGRAM_WIDTH: u32 = 640;
+pub const HISTOGRAM_HEIGHT: u32 = 240;
+
+//INFO: how many log spaced points the curves are drawn from, independent of the table
+pub const PLOT_POINTS: usize = 400;
+
+//INFO: a resonance dip with no resistor goes to 0 Ω, the |Z| axis stops this many decades under the peak
+const MAGNITUDE_DECADES: i32 = 12;
+
+const MARGIN: f64 = 10.0;
+const PANEL_GAP: f64 = 20.0;
+
+const BACKGROUND: Rgb8Pixel = Rgb8Pixel { r: 255, g: 255, b: 255 };
+const GRID: Rgb8Pixel = Rgb8Pixel { r: 225, g: 225, b: 225 };
+const AXIS: Rgb8Pixel = Rgb8Pixel { r: 110, g: 110, b: 110 };
+const MAGNITUDE_COLOR: Rgb8Pixel = Rgb8Pixel { r: 30, g: 90, b: 200 };
+const PHASE_COLOR: Rgb8Pixel = Rgb8Pixel { r: 20, g: 150, b: 60 };
+const MARKER_COLOR: Rgb8Pixel = Rgb8Pixel { r: 220, g: 40, b: 40 };
+const BAR_COLOR: Rgb8Pixel = Rgb8Pixel { r: 30, g: 90, b: 200 };
+
+/// A rendered Bode plot, |Z| on top and phase below, both against log frequency.
+pub struct BodePlot {
+	pub image: Image,
+	/// Decades the |Z| axis spans, 10^low Ω at the bottom to 10^high Ω at the top.
+	pub magnitude_decades: (i32, i32),
+}
+
+/// Draws the sweep, frequencies ascending, with a marker at `f0` when it falls inside the range.
+pub fn render_bode(points: &[SweepPoint], f0: &BFloat) -> BodePlot {
+	let mut canvas = Canvas::new(PLOT_WIDTH, PLOT_HEIGHT);
+
+	let frequencies = points.iter().map(|point| point.frequency.to_f64().log10()).collect::<Vec<f64>>();
+	let magnitudes = points.iter().map(|point| point.impedance.to_f64()).collect::<Vec<f64>>();
+	let phases = points.iter().map(|point| point.phase.to_degrees().to_f64()).collect::<Vec<f64>>();
+
+	let x_range = (
+		frequencies.first().copied().unwrap_or(0.0),
+		frequencies.last().copied().unwrap_or(1.0),
+	);
+	let magnitude_decades = decade_range(&magnitudes);
+
+	let panel_height = (PLOT_HEIGHT as f64 - 2.0 * MARGIN - PANEL_GAP) / 2.0;
+	let panel_width = PLOT_WIDTH as f64 - 2.0 * MARGIN;
+
+	let magnitude_panel = Panel {
+		left: MARGIN,
+		top: MARGIN,
+		width: panel_width,
+		height: panel_height,
+		x_range,
+		y_range: (magnitude_decades.0 as f64, magnitude_decades.1 as f64),
+	};
+	let phase_panel = Panel {
+		top: MARGIN + panel_height + PANEL_GAP,
+		y_range: (-90.0, 90.0),
+		..magnitude_panel
+	};
+
+	magnitude_panel.draw_grid(&mut canvas, (magnitude_decades.0..=magnitude_decades.1).map(f64::from));
+	phase_panel.draw_grid(&mut canvas, [-90.0, -45.0, 0.0, 45.0, 90.0]);
+
+	let log_magnitudes = magnitudes.iter().map(|magnitude| magnitude.log10()).collect::<Vec<f64>>();
+
+	magnitude_panel.draw_curve(&mut canvas, &frequencies, &log_magnitudes, MAGNITUDE_COLOR);
+	phase_panel.draw_curve(&mut canvas, &frequencies, &phases, PHASE_COLOR);
+
+	let f0 = f0.to_f64().log10();
+
+	if f0.is_finite() && f0 >= x_range.0.min(x_range.1) && f0 <= x_range.0.max(x_range.1) {
+		magnitude_panel.draw_marker(&mut canvas, f0);
+		phase_panel.draw_marker(&mut canvas, f0);
+	}
+
+	BodePlot {
+		image: Image::from_rgb8(canvas.buffer),
+		magnitude_decades,
+	}
+}
+
+/// Draws one bar per count, as tall as its share of the largest, left to right from the minimum.
+pub fn render_histogram(counts: &[usize]) -> Image {
+	let mut canvas = Canvas::new(HISTOGRAM_WIDTH, HISTOGRAM_HEIGHT);
+
+	let panel = Panel {
+		left: MARGIN,
+		top: MARGIN,
+		width: HISTOGRAM_WIDTH as f64 - 2.0 * MARGIN,
+		height: HISTOGRAM_HEIGHT as f64 - 2.0 * MARGIN,
+		x_range: (0.0, counts.len().max(1) as f64),
+		y_range: (0.0, counts.iter().copied().max().unwrap_or(0).max(1) as f64),
+	};
+
+	for (index, count) in counts.iter().enumerate() {
+		//INFO: a pixel of gap between bars
+		let (left, right) = (panel.x(index as f64) + 1.0, panel.x(index as f64 + 1.0) - 1.0);
+		canvas.fill(left, panel.y(*count as f64), right, panel.bottom(), BAR_COLOR);
+	}
+
+	canvas.line((panel.left, panel.bottom()), (panel.right(), panel.bottom()), AXIS);
+
+	Image::from_rgb8(canvas.buffer)
+}
+
+//INFO: whole decades around the finite, positive values, at least one decade tall
+fn decade_range(values: &[f64]) -> (i32, i32) {
+	let logs = values
+		.iter()
+		.filter(|value| value.is_finite() && **value > 0.0)
+		.map(|value| value.log10());
+
+	let (low, high) = logs.fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), log| (low.min(log), high.max(log)));
+
+	if !low.is_finite() || !high.is_finite() {
+		return (0, 1);
+	}
+
+	let high = high.ceil() as i32;
+	let low = (low.floor() as i32).max(high - MAGNITUDE_DECADES);
+
+	(low, high.max(low + 1))
+}
+
+struct Canvas {
+	buffer: SharedPixelBuffer<Rgb8Pixel>,
+}
+
+impl Canvas {
+	fn new(width: u32, height: u32) -> Self {
+		let mut buffer = SharedPixelBuffer::<Rgb8Pixel>::new(width, height);
+		buffer.make_mut_slice().fill(BACKGROUND);
+
+		Canvas { buffer }
+	}
+
+	fn set(&mut self, x: i64, y: i64, color: Rgb8Pixel) {
+		let (width, height) = (self.buffer.width() as i64, self.buffer.height() as i64);
+
+		if (0..width).contains(&x) && (0..height).contains(&y) {
+			self.buffer.make_mut_slice()[(y * width + x) as usize] = color;
+		}
+	}
+
+	fn fill(&mut self, left: f64, top: f64, right: f64, bottom: f64, color: Rgb8Pixel) {
+		for y in (top.round() as i64)..(bottom.round() as i64) {
+			for x in (left.round() as i64)..=(right.round() as i64) {
+				self.set(x, y, color);
+			}
+		}
+	}
+
+	//INFO: Bresenham, thickened by a pixel so curves stay visible when the image is scaled down
+	fn line(&mut self, from: (f64, f64), to: (f64, f64), color: Rgb8Pixel) {
+		let (mut x0, mut y0) = (from.0.round() as i64, from.1.round() as i64);
+		let (x1, y1) = (to.0.round() as i64, to.1.round() as i64);
+
+		let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+		let (sx, sy) = (if x0 < x1 { 1 } else { -1 }, if y0 < y1 { 1 } else { -1 });
+		let mut error = dx + dy;
+
+		loop {
+			self.set(x0, y0, color);
+			self.set(x0 + 1, y0, color);
+			self.set(x0, y0 + 1, color);
+
+			if x0 == x1 && y0 == y1 {
+				break;
+			}
+
+			let doubled = 2 * error;
+			if doubled >= dy {
+				error += dy;
+				x0 += sx;
+			}
+			if doubled <= dx {
+				error += dx;
+				y0 += sy;
+			}
+		}
+	}
+}
+
+//INFO: a rectangle of the image and the values its edges stand for, x in log10 Hz on a Bode plot
+#[derive(Clone, Copy)]
+struct Panel {
+	left: f64,
+	top: f64,
+	width: f64,
+	height: f64,
+	x_range: (f64, f64),
+	y_range: (f64, f64),
+}
+
+impl Panel {
+	fn x(&self, value: f64) -> f64 {
+		self.left + (value - self.x_range.0) / (self.x_range.1 - self.x_range.0) * self.width
+	}
+
+	//INFO: clamped, so values off the axis run along its edge instead of vanishing
+	fn y(&self, value: f64) -> f64 {
+		let fraction = ((value - self.y_range.0) / (self.y_range.1 - self.y_range.0)).clamp(0.0, 1.0);
+		self.top + (1.0 - fraction) * self.height
+	}
+
+	fn right(&self) -> f64 {
+		self.left + self.width
+	}
+
+	fn bottom(&self) -> f64 {
+		self.top + self.height
+	}
+
+	fn draw_grid(&self, canvas: &mut Canvas, y_lines: impl IntoIterator<Item = f64>) {
+		for y in y_lines {
+			canvas.line((self.left, self.y(y)), (self.right(), self.y(y)), GRID);
+		}
+
+		let (low, high) = (self.x_range.0.min(self.x_range.1), self.x_range.0.max(self.x_range.1));
+
+		for decade in (low.ceil() as i32)..=(high.floor() as i32) {
+			let x = self.x(decade as f64);
+			canvas.line((x, self.top), (x, self.bottom()), GRID);
+		}
+
+		canvas.line((self.left, self.top), (self.left, self.bottom()), AXIS);
+		canvas.line((self.left, self.bottom()), (self.right(), self.bottom()), AXIS);
+	}
+
+	fn draw_curve(&self, canvas: &mut Canvas, xs: &[f64], ys: &[f64], color: Rgb8Pixel) {
+		let points = xs
+			.iter()
+			.zip(ys)
+			.map(|(x, y)| (self.x(*x), self.y(*y)))
+			.filter(|(x, y)| x.is_finite() && y.is_finite())
+			.collect::<Vec<(f64, f64)>>();
+
+		for pair in points.windows(2) {
+			canvas.line(pair[0], pair[1], color);
+		}
+	}
+
+	fn draw_marker(&self, canvas: &mut Canvas, x: f64) {
+		let x = self.x(x);
+		canvas.line((x, self.top), (x, self.bottom()), MARKER_COLOR);
+	}
+}
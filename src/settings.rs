@@ -0,0 +1,346 @@
+use std::{error::Error, fs, path::Path};
+
+use calcularoty_core::precision::{DEFAULT_PRECISION, PRECISION_PRESETS, ROUNDING_MODES};
+
+use crate::history::{ImpRecord, LcRecord, NetRecord};
+
+const SETTINGS_FILE: &str = "settings.conf";
+
+//INFO: what the app looked like when it was last closed, plus the user preferences
+#[derive(Clone, Debug)]
+pub struct Settings {
+	pub page: i32,
+	pub music_muted: bool,
+	pub music_on_start: bool,
+	pub music_volume: f32,
+	pub precision: usize,
+	pub rounding: String,
+	//INFO: name of the series computed parts are snapped to, empty when off
+	pub e_series: String,
+	pub lc: LcRecord,
+	pub imp: ImpRecord,
+	pub net: NetRecord,
+	pub coil: CoilSettings,
+	pub core: CoreSettings,
+	pub cap: CapSettings,
+}
+
+//INFO: the coil page keeps no history, only its inputs are remembered
+#[derive(Clone, Debug)]
+pub struct CoilSettings {
+	pub shape: String,
+	pub turns: String,
+	pub diameter: String,
+	pub diameter_unit: String,
+	pub length: String,
+	pub length_unit: String,
+	pub wire: String,
+	pub wire_unit: String,
+	pub target: String,
+	pub target_unit: String,
+}
+
+//INFO: the core page keeps no history either, the catalog itself is in its own file
+#[derive(Clone, Debug)]
+pub struct CoreSettings {
+	pub name: String,
+	pub al: String,
+	pub al_unit: String,
+	pub turns: String,
+	pub l_unit: String,
+	pub target: String,
+	pub target_unit: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CapSettings {
+	pub shape: String,
+	pub dielectric: String,
+	pub permittivity: String,
+	pub area: String,
+	pub area_unit: String,
+	pub separation: String,
+	pub separation_unit: String,
+	pub inner: String,
+	pub inner_unit: String,
+	pub outer: String,
+	pub outer_unit: String,
+	pub length: String,
+	pub length_unit: String,
+	pub target: String,
+	pub target_unit: String,
+	pub unknown: String,
+}
+
+//INFO: a getter and a setter for each "key=value" line of the file
+type Field = (&'static str, fn(&Settings) -> String, fn(&mut Settings, String));
+
+macro_rules! text {
+	($key:literal, $($field:ident).+) => {
+		($key, |s| s.$($field).+.clone(), |s, value| s.$($field).+ = value)
+	};
+}
+
+//INFO: a value that does not parse leaves the default in place
+macro_rules! parsed {
+	($key:literal, $($field:ident).+) => {
+		($key, |s| s.$($field).+.to_string(), |s, value| s.$($field).+ = value.parse().unwrap_or(s.$($field).+))
+	};
+}
+
+//INFO: a hand-edited precision past the largest preset would make every calculation crawl
+const MAX_PRECISION: usize = PRECISION_PRESETS[PRECISION_PRESETS.len() - 1];
+
+const FIELDS: &[Field] = &[
+	parsed!("page", page),
+	parsed!("music_muted", music_muted),
+	parsed!("music_on_start", music_on_start),
+	parsed!("music_volume", music_volume),
+	("precision", |s| s.precision.to_string(), |s, value| s.precision = value.parse().map(|bits: usize| bits.min(MAX_PRECISION)).unwrap_or(s.precision)),
+	text!("rounding", rounding),
+	text!("e_series", e_series),
+	text!("lc.input1_text", lc.input1_text),
+	text!("lc.input1_unit", lc.input1_unit),
+	text!("lc.input2_text", lc.input2_text),
+	text!("lc.input2_unit", lc.input2_unit),
+	text!("lc.output_unit", lc.output_unit),
+	text!("imp.inductance", imp.inductance),
+	text!("imp.capacitance", imp.capacitance),
+	text!("imp.frequency", imp.frequency),
+	text!("imp.resistance", imp.resistance),
+	text!("imp.l_unit", imp.l_unit),
+	text!("imp.c_unit", imp.c_unit),
+	text!("imp.f_unit", imp.f_unit),
+	text!("imp.r_unit", imp.r_unit),
+	parsed!("imp.circuit", imp.circuit),
+	text!("net.network", net.network),
+	text!("net.frequency", net.frequency),
+	text!("net.f_unit", net.f_unit),
+	text!("coil.shape", coil.shape),
+	text!("coil.turns", coil.turns),
+	text!("coil.diameter", coil.diameter),
+	text!("coil.diameter_unit", coil.diameter_unit),
+	text!("coil.length", coil.length),
+	text!("coil.length_unit", coil.length_unit),
+	text!("coil.wire", coil.wire),
+	text!("coil.wire_unit", coil.wire_unit),
+	text!("coil.target", coil.target),
+	text!("coil.target_unit", coil.target_unit),
+	text!("core.name", core.name),
+	text!("core.al", core.al),
+	text!("core.al_unit", core.al_unit),
+	text!("core.turns", core.turns),
+	text!("core.l_unit", core.l_unit),
+	text!("core.target", core.target),
+	text!("core.target_unit", core.target_unit),
+	text!("cap.shape", cap.shape),
+	text!("cap.dielectric", cap.dielectric),
+	text!("cap.permittivity", cap.permittivity),
+	text!("cap.area", cap.area),
+	text!("cap.area_unit", cap.area_unit),
+	text!("cap.separation", cap.separation),
+	text!("cap.separation_unit", cap.separation_unit),
+	text!("cap.inner", cap.inner),
+	text!("cap.inner_unit", cap.inner_unit),
+	text!("cap.outer", cap.outer),
+	text!("cap.outer_unit", cap.outer_unit),
+	text!("cap.length", cap.length),
+	text!("cap.length_unit", cap.length_unit),
+	text!("cap.target", cap.target),
+	text!("cap.target_unit", cap.target_unit),
+	text!("cap.unknown", cap.unknown),
+];
+
+impl Default for Settings {
+	fn default() -> Self {
+		Settings {
+			page: 0,
+			music_muted: false,
+			music_on_start: true,
+			music_volume: 1.0,
+			precision: DEFAULT_PRECISION,
+			rounding: ROUNDING_MODES[0].0.to_string(),
+			e_series: String::new(),
+			lc: LcRecord {
+				input1_text: String::new(),
+				input1_unit: String::new(),
+				input2_text: String::new(),
+				input2_unit: String::new(),
+				output_unit: String::new(),
+				result: String::new(),
+			},
+			imp: ImpRecord {
+				inductance: String::new(),
+				capacitance: String::new(),
+				frequency: String::new(),
+				resistance: String::new(),
+				l_unit: "mH".to_string(),
+				c_unit: "μF".to_string(),
+				f_unit: "Hz".to_string(),
+				r_unit: "Ω".to_string(),
+				circuit: -1,
+				outputs: Vec::new(),
+			},
+			net: NetRecord {
+				network: String::new(),
+				frequency: String::new(),
+				f_unit: "Hz".to_string(),
+				outputs: Vec::new(),
+			},
+			coil: CoilSettings {
+				shape: "Single layer".to_string(),
+				turns: String::new(),
+				diameter: String::new(),
+				diameter_unit: "mm".to_string(),
+				length: String::new(),
+				length_unit: "mm".to_string(),
+				wire: String::new(),
+				wire_unit: "mm".to_string(),
+				target: String::new(),
+				target_unit: "μH".to_string(),
+			},
+			core: CoreSettings {
+				name: String::new(),
+				al: String::new(),
+				al_unit: "nH".to_string(),
+				turns: String::new(),
+				l_unit: "μH".to_string(),
+				target: String::new(),
+				target_unit: "μH".to_string(),
+			},
+			cap: CapSettings {
+				shape: "Parallel plate".to_string(),
+				dielectric: "Air".to_string(),
+				permittivity: "1.00059".to_string(),
+				area: String::new(),
+				area_unit: "mm²".to_string(),
+				separation: String::new(),
+				separation_unit: "mm".to_string(),
+				inner: String::new(),
+				inner_unit: "mm".to_string(),
+				outer: String::new(),
+				outer_unit: "mm".to_string(),
+				length: String::new(),
+				length_unit: "m".to_string(),
+				target: String::new(),
+				target_unit: "pF".to_string(),
+				unknown: "Separation".to_string(),
+			},
+		}
+	}
+}
+
+impl Settings {
+	//INFO: a missing or unreadable file just means a fresh start
+	pub fn load(dir: Option<&Path>) -> Settings {
+		let Some(dir) = dir else {
+			return Settings::default();
+		};
+
+		match fs::read_to_string(dir.join(SETTINGS_FILE)) {
+			Ok(text) => Settings::from_text(&text),
+			Err(_) => Settings::default(),
+		}
+	}
+
+	pub fn save(&self, dir: Option<&Path>) -> Result<(), Box<dyn Error>> {
+		let Some(dir) = dir else {
+			return Ok(());
+		};
+
+		fs::create_dir_all(dir)?;
+		fs::write(dir.join(SETTINGS_FILE), self.to_text())?;
+
+		Ok(())
+	}
+
+	//INFO: one "key=value" per line, unknown keys and bad values are ignored
+	fn from_text(text: &str) -> Settings {
+		let mut settings = Settings::default();
+
+		for (key, value) in text.lines().filter_map(|line| line.split_once('=')) {
+			if let Some((_, _, set)) = FIELDS.iter().find(|(name, _, _)| *name == key.trim()) {
+				set(&mut settings, value.to_string());
+			}
+		}
+
+		settings
+	}
+
+	fn to_text(&self) -> String {
+		FIELDS
+			.iter()
+			.map(|(key, get, _)| format!("{key}={}\n", get(self).replace('\n', " ")))
+			.collect()
+	}
+}
+
+//INFO: the per-user config directory, on Android the app's internal storage is passed in instead
+#[cfg(not(target_os = "android"))]
+pub fn config_dir() -> Option<std::path::PathBuf> {
+	use std::path::PathBuf;
+
+	let env_dir = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+
+	let base = if cfg!(target_os = "windows") {
+		env_dir("APPDATA")?
+	} else if cfg!(target_os = "macos") {
+		env_dir("HOME")?.join("Library").join("Application Support")
+	} else {
+		env_dir("XDG_CONFIG_HOME").or_else(|| env_dir("HOME").map(|home| home.join(".config")))?
+	};
+
+	Some(base.join("the_calcularoty"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Settings;
+
+	#[test]
+	fn text_round_trip() {
+		let mut settings = Settings {
+			page: 7,
+			music_muted: true,
+			music_volume: 0.25,
+			precision: 256,
+			rounding: "Down".to_string(),
+			..Settings::default()
+		};
+		settings.lc.input1_text = "4u7".to_string();
+		settings.net.network = "50Ω + (10mH | 100nF)".to_string();
+		settings.cap.area_unit = "cm²".to_string();
+
+		let text = settings.to_text();
+		let read = Settings::from_text(&text);
+
+		assert_eq!(read.to_text(), text);
+		assert_eq!(read.page, 7);
+		assert!(read.music_muted);
+		assert_eq!(read.music_volume, 0.25);
+		assert_eq!(read.net.network, "50Ω + (10mH | 100nF)");
+
+		//INFO: a line break in a value must not start a key of its own
+		settings.lc.input1_text = "1\npage=3".to_string();
+		assert_eq!(Settings::from_text(&settings.to_text()).page, 7);
+	}
+
+	#[test]
+	fn bad_lines_keep_defaults() {
+		let defaults = Settings::default();
+		let read = Settings::from_text("page=four\nmusic_volume=loud\nprecision=\nno equals sign\nunknown.key=1\n=5\nmusic_on_start=false\n");
+
+		assert_eq!(read.page, defaults.page);
+		assert_eq!(read.music_volume, defaults.music_volume);
+		assert_eq!(read.precision, defaults.precision);
+		assert!(!read.music_on_start);
+
+		assert_eq!(Settings::from_text("").to_text(), defaults.to_text());
+	}
+
+	#[test]
+	fn precision_is_clamped() {
+		assert_eq!(Settings::from_text("precision=4000000000\n").precision, 8192);
+		assert_eq!(Settings::from_text("precision=2048\n").precision, 2048);
+	}
+}
@@ -1,5 +1,14 @@
-use slint::SharedString;
+use phf::OrderedMap;
+use slint::{SharedString, ToSharedString};
 
 pub trait MapToSharedStringVec {
 	fn to_shared_string_vec(&self) -> Vec<SharedString>;
 }
+
+impl<T> MapToSharedStringVec for OrderedMap<&str, T> {
+	fn to_shared_string_vec(&self) -> Vec<SharedString> {
+	    self.keys()
+			.map(|x| x.to_shared_string())
+			.collect::<Vec<SharedString>>()
+	}
+}